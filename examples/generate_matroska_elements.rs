@@ -0,0 +1,145 @@
+use std::fmt::Write;
+use std::{env, fs};
+
+use mkvedit::ebml::schema::{EBML_ELEMENTS, ElementDef, ElementType, XmlSchema};
+
+// Generates `src/matroska/elements.rs` from the Matroska EBMLSchema XML file
+// (`ebml_matroska.xml` from https://github.com/ietf-wg-cellar/matroska-specification)
+//
+// Usage: cargo run --example=generate_matroska_elements -- ebml_matroska.xml src/matroska/elements.rs
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    let schema = XmlSchema::from_file(&args[1]).unwrap();
+    let elements: Vec<&ElementDef> = schema
+        .elements()
+        .filter(|element| EBML_ELEMENTS.iter().all(|ebml| ebml.id != element.id))
+        .collect();
+
+    let output = generate(&schema, &elements).unwrap();
+    match args.get(2) {
+        Some(path) => fs::write(path, output).unwrap(),
+        None => print!("{output}"),
+    }
+}
+
+// Converts an element name to a constant name, e.g. `SegmentUUID` to `SEGMENT_UUID_ID`
+fn constant_name(name: &str) -> String {
+    let chars: Vec<char> = name.chars().filter(char::is_ascii_alphanumeric).collect();
+    let mut constant = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 && c.is_ascii_uppercase() {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(char::is_ascii_lowercase);
+            if previous.is_ascii_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_ascii_uppercase() && next_is_lower)
+            {
+                constant.push('_');
+            }
+        }
+        constant.push(c.to_ascii_uppercase());
+    }
+    constant.push_str("_ID");
+    constant
+}
+
+// Formats an ID as a hex literal with 4-digit groups, e.g. `0x002A_D7B1`
+fn id_literal(id: u64) -> String {
+    if id <= 0xFFFF {
+        return format!("{id:#X}").replace("0X", "0x");
+    }
+    let digits = format!("{id:08X}");
+    let (high, low) = digits.split_at(digits.len() - 4);
+    format!("0x{high}_{low}")
+}
+
+fn option_literal(value: Option<&str>) -> String {
+    match value {
+        Some(value) => format!("Some(Cow::Borrowed({value:?}))"),
+        None => "None".to_string(),
+    }
+}
+
+fn type_literal(element_type: ElementType) -> &'static str {
+    match element_type {
+        ElementType::Integer => "ElementType::Integer",
+        ElementType::UnsignedInteger => "ElementType::UnsignedInteger",
+        ElementType::Float => "ElementType::Float",
+        ElementType::String => "ElementType::String",
+        ElementType::Utf8 => "ElementType::Utf8",
+        ElementType::Date => "ElementType::Date",
+        ElementType::Master => "ElementType::Master",
+        ElementType::Binary => "ElementType::Binary",
+    }
+}
+
+fn generate(schema: &XmlSchema, elements: &[&ElementDef]) -> Result<String, std::fmt::Error> {
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        "// Generated by `cargo run --example=generate_matroska_elements -- ebml_matroska.xml src/matroska/elements.rs`"
+    )?;
+    writeln!(
+        out,
+        "// from the EBML schema for docType {:?} version {}, do not edit manually",
+        schema.doc_type(),
+        schema.version()
+    )?;
+    writeln!(out)?;
+    writeln!(out, "use std::borrow::Cow;")?;
+    writeln!(out)?;
+    writeln!(out, "use crate::ebml::schema::{{ElementDef, ElementType}};")?;
+    writeln!(out)?;
+
+    for element in elements {
+        writeln!(
+            out,
+            "pub const {}: u64 = {};",
+            constant_name(&element.name),
+            id_literal(element.id)
+        )?;
+    }
+    writeln!(out)?;
+
+    writeln!(out, "pub const MATROSKA_ELEMENTS: &[ElementDef] = &[")?;
+    for element in elements {
+        writeln!(
+            out,
+            "    ElementDef {{ id: {}, name: Cow::Borrowed({:?}), path: Cow::Borrowed({:?}), element_type: {}, default: {}, range: {}, min_occurs: {}, max_occurs: {:?}, recurring: {}, unknown_size_allowed: {} }},",
+            constant_name(&element.name),
+            element.name,
+            element.path,
+            type_literal(element.element_type),
+            option_literal(element.default.as_deref()),
+            option_literal(element.range.as_deref()),
+            element.min_occurs,
+            element.max_occurs,
+            element.recurring,
+            element.unknown_size_allowed,
+        )?;
+    }
+    writeln!(out, "];")?;
+    writeln!(out)?;
+
+    writeln!(
+        out,
+        "// Returns the definition of the Matroska element `id`"
+    )?;
+    writeln!(out, "#[allow(clippy::too_many_lines)]")?;
+    writeln!(
+        out,
+        "pub fn matroska_element(id: u64) -> Option<&'static ElementDef> {{"
+    )?;
+    writeln!(out, "    let index = match id {{")?;
+    for (index, element) in elements.iter().enumerate() {
+        writeln!(out, "        {} => {index},", constant_name(&element.name))?;
+    }
+    writeln!(out, "        _ => return None,")?;
+    writeln!(out, "    }};")?;
+    writeln!(out, "    Some(&MATROSKA_ELEMENTS[index])")?;
+    writeln!(out, "}}")?;
+
+    Ok(out)
+}
@@ -1,12 +1,22 @@
 use std::{env, fs::File};
 
+use mkvedit::ebml::{BufferedSource, ReaderLimits};
 use mkvedit::matroska::{MatroskaDocument, print_matroska_tree};
 
 fn main() {
     let args: Vec<String> = env::args().collect();
 
     let file = File::open(&args[1]).unwrap();
-    let matroska_doc = MatroskaDocument::parse_from(file).unwrap();
+    let mut diagnostics = Vec::new();
+    let matroska_doc = MatroskaDocument::parse_with(
+        BufferedSource::new(file),
+        ReaderLimits::default(),
+        &mut diagnostics,
+    )
+    .unwrap();
+    for diagnostic in &diagnostics {
+        eprintln!("{diagnostic}");
+    }
     println!("{}", print_matroska_tree(&matroska_doc, true).unwrap());
     println!("{}", print_matroska_tree(&matroska_doc, false).unwrap());
 }
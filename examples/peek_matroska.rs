@@ -0,0 +1,29 @@
+use std::{env, fs::File};
+
+use mkvedit::ebml::{EbmlCursor, EbmlReader};
+use mkvedit::matroska::{CLUSTER_ID, MatroskaSchema, SEGMENT_ID};
+
+// Lists the top-level elements and the Segment children up to the first Cluster,
+// without reading the rest of the file
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    let file = File::open(&args[1]).unwrap();
+    let mut ebml_reader = EbmlReader::new(file);
+    let mut cursor = EbmlCursor::new(&mut ebml_reader, &MatroskaSchema);
+
+    while let Some(header) = cursor.next_element().unwrap() {
+        println!("{:#X} {:?}", header.id, header.header);
+        if header.id != SEGMENT_ID {
+            continue;
+        }
+        cursor.enter().unwrap();
+        while let Some(child) = cursor.next_element().unwrap() {
+            println!("  {:#X} {:?}", child.id, child.header);
+            if child.id == CLUSTER_ID {
+                return;
+            }
+        }
+        cursor.leave().unwrap();
+    }
+}
@@ -0,0 +1,31 @@
+use std::{env, fs::File};
+
+use mkvedit::ebml::{self, EbmlSchema, Query, primitives};
+use mkvedit::matroska::MatroskaSchema;
+
+// Prints the elements of a `.mkv` file matching a query, e.g. `Segment/Tracks/TrackEntry[TrackType=2]/Language`
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    let schema = MatroskaSchema;
+    let query = Query::parse(&args[2], &schema).unwrap();
+
+    let file = File::open(&args[1]).unwrap();
+    let mut ebml_reader = ebml::EbmlReader::new(file);
+    let root = ebml::read_root(&mut ebml_reader, &schema).unwrap();
+
+    for element in query.find_all(&mut ebml_reader, &root).unwrap() {
+        let name = schema.name(element.id).unwrap_or("Unknown");
+        let value = match schema.element_type(element.id) {
+            Some(element_type) => {
+                let bytes = ebml_reader.read_range(&element.data).unwrap().into_owned();
+                primitives::parse_value(element_type, bytes).unwrap()
+            }
+            None => None,
+        };
+        match value {
+            Some(value) => println!("{name} at {}: {value}", element.header.start),
+            None => println!("{name} at {}", element.header.start),
+        }
+    }
+}
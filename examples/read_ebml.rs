@@ -1,22 +1,46 @@
 use std::{env, fs::File};
 
-use mkvedit::ebml;
+use mkvedit::ebml::{self, EbmlSchema, XmlSchema};
 
 struct SimpleEbmlSchema;
 
 impl ebml::EbmlSchema for SimpleEbmlSchema {
     // Only the EBML header is a master element in this simple schema
-    fn is_master(id: u64) -> bool {
-        id == 0x1A45DFA3
+    fn is_master(&self, id: u64) -> bool {
+        id == 0x1A45_DFA3
     }
 }
 
 // Reads an EBML file specified as the first command line argument
+// An EBMLSchema XML file (e.g. `ebml_matroska.xml`) can be given as the second argument
 fn main() {
     let args: Vec<String> = env::args().collect();
 
+    let xml_schema = args.get(2).map(|path| XmlSchema::from_file(path).unwrap());
+    let schema: &dyn EbmlSchema = match &xml_schema {
+        Some(xml_schema) => xml_schema,
+        None => &SimpleEbmlSchema,
+    };
+
     let file = File::open(&args[1]).unwrap();
     let mut ebml_reader = ebml::EbmlReader::new(file);
-    let root = ebml::read_root::<SimpleEbmlSchema, _>(&mut ebml_reader).unwrap();
-    dbg!(root);
+    let root = ebml::read_root(&mut ebml_reader, schema).unwrap();
+
+    for mismatch in ebml::verify_crcs(&mut ebml_reader, &root).unwrap() {
+        println!(
+            "CRC-32 mismatch in element {:#X} at {}: stored {:X?}, computed {:X}",
+            mismatch.id, mismatch.position, mismatch.stored, mismatch.computed
+        );
+    }
+    for issue in ebml::validate(&mut ebml_reader, schema, &root).unwrap() {
+        let name = schema.name(issue.id).unwrap_or("unknown element");
+        println!(
+            "{name} ({:#X}) at {}: {:?}",
+            issue.id, issue.position, issue.kind
+        );
+    }
+    print!(
+        "{}",
+        ebml::print_ebml_tree(&mut ebml_reader, schema, &root, true).unwrap()
+    );
 }
@@ -0,0 +1,11 @@
+use std::io;
+
+use mkvedit::ebml::EbmlStreamReader;
+use mkvedit::matroska::MatroskaSchema;
+
+// Reads a Matroska stream from stdin without seeking, e.g. `ffmpeg ... -f matroska - | ...`
+fn main() {
+    let mut stream_reader = EbmlStreamReader::new(io::stdin().lock());
+    let root = stream_reader.read_root(&MatroskaSchema).unwrap();
+    dbg!(root);
+}
@@ -7,14 +7,14 @@ use mkvedit::ebml::element::Element;
 struct SimpleEbmlSchema;
 
 impl ebml::EbmlSchema for SimpleEbmlSchema {
-    fn is_master(id: u64) -> bool {
-        id == 0x1A45DFA3 // EBML Header
+    fn is_master(&self, id: u64) -> bool {
+        id == 0x1A45_DFA3 // EBML Header
     }
 }
 
 fn main() {
     let ebml_tree = Element::Master {
-        id: 0x1A45DFA3, // EBML Header
+        id: 0x1A45_DFA3, // EBML Header
         children: vec![
             Element::Raw {
                 id: 0x4286,       // EBML Version
@@ -36,6 +36,6 @@ fn main() {
 
     let cursor = Cursor::new(bytes);
     let mut ebml_reader = EbmlReader::new(cursor);
-    let root = ebml::read_root::<SimpleEbmlSchema, _>(&mut ebml_reader).unwrap();
+    let root = ebml::read_root(&mut ebml_reader, &SimpleEbmlSchema).unwrap();
     dbg!(root);
 }
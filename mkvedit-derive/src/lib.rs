@@ -0,0 +1,446 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    Data, DeriveInput, Expr, Fields, GenericArgument, Ident, PathArguments, Type,
+    parse_macro_input, spanned::Spanned,
+};
+
+// Derives `MatroskaElement`, `ToElement` and `TreePrintable` for a typed Matroska element
+//
+// The struct is annotated with the ID of the element, each field with the ID of a child:
+//
+//     #[derive(MatroskaElement)]
+//     #[matroska(id = INFO_ID)]
+//     pub struct Info {
+//         pub raw: ParsedElement,
+//         #[matroska(id = TIMESTAMP_SCALE_ID)]
+//         pub timestamp_scale: OptionalField<u64>,
+//         #[matroska(id = TITLE_ID)]
+//         pub title: Option<Field<String>>,
+//     }
+//
+// The multiplicity of a child follows from the type of its field:
+// - `Field<T>` and other elements are mandatory, missing ones fail with `MissingElement`
+// - `OptionalField<T>` takes the schema default when absent, or `default = expr` if given
+// - `Option<_>` is optional and `Vec<_>` collects all occurrences
+// Fields of type `Field<T>` (also inside `Option` and `Vec`) are leaf values parsed with
+// `FieldValue`, all others are parsed as elements with `MatroskaReader::parse_element`
+//
+// `ToElement` writes the children in the order they were read, but children without a field,
+// such as CRC-32, Void and unknown elements, are not kept and are missing from the output
+//
+// The struct must have a `raw: ParsedElement` field, and the generated code refers to
+// items of the `mkvedit` crate with `crate::` paths, so it can only be used inside it
+#[proc_macro_derive(MatroskaElement, attributes(matroska))]
+pub fn derive_matroska_element(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+// How the children of a field are stored
+enum Multiplicity {
+    // `Field<T>` or an element
+    Mandatory,
+    // `OptionalField<T>`, with a default value overriding the schema default
+    Default(Option<Expr>),
+    Optional,
+    Multiple,
+}
+
+struct ChildField {
+    ident: Ident,
+    id: Expr,
+    multiplicity: Multiplicity,
+    // Whether the field holds `Field<T>` values rather than elements
+    leaf: bool,
+}
+
+#[derive(Default)]
+struct Attributes {
+    id: Option<Expr>,
+    default: Option<Expr>,
+}
+
+fn parse_attributes(attrs: &[syn::Attribute]) -> syn::Result<Attributes> {
+    let mut attributes = Attributes::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("matroska")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("id") {
+                attributes.id = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("default") {
+                attributes.default = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("expected `id` or `default`"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(attributes)
+}
+
+// Returns the last path segment of `ty` and its first generic argument, e.g. `Option` and `T`
+fn type_wrapper(ty: &Type) -> Option<(&Ident, Option<&Type>)> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let argument = match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => {
+            arguments.args.iter().find_map(|argument| match argument {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+        }
+        _ => None,
+    };
+    Some((&segment.ident, argument))
+}
+
+fn is_field(ty: &Type) -> bool {
+    type_wrapper(ty).is_some_and(|(ident, _)| ident == "Field")
+}
+
+fn child_field(field: &syn::Field, ident: Ident) -> syn::Result<ChildField> {
+    let Attributes { id, mut default } = parse_attributes(&field.attrs)?;
+    let id = id.ok_or_else(|| syn::Error::new(field.span(), "missing `#[matroska(id = ...)]`"))?;
+
+    let (multiplicity, leaf) = match type_wrapper(&field.ty) {
+        Some((wrapper, _)) if wrapper == "OptionalField" => {
+            (Multiplicity::Default(default.take()), true)
+        }
+        Some((wrapper, Some(inner))) if wrapper == "Option" => {
+            (Multiplicity::Optional, is_field(inner))
+        }
+        Some((wrapper, Some(inner))) if wrapper == "Vec" => {
+            (Multiplicity::Multiple, is_field(inner))
+        }
+        _ => (Multiplicity::Mandatory, is_field(&field.ty)),
+    };
+    if default.is_some() {
+        return Err(syn::Error::new(
+            field.span(),
+            "`default` is only supported for `OptionalField`",
+        ));
+    }
+
+    Ok(ChildField {
+        ident,
+        id,
+        multiplicity,
+        leaf,
+    })
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let id = parse_attributes(&input.attrs)?
+        .id
+        .ok_or_else(|| syn::Error::new(input.span(), "missing `#[matroska(id = ...)]`"))?;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(input.span(), "expected a struct"));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new(input.span(), "expected named fields"));
+    };
+
+    let mut has_raw = false;
+    let mut children = Vec::new();
+    for field in &fields.named {
+        let ident = field.ident.clone().expect("named field");
+        if ident == "raw" {
+            has_raw = true;
+        } else {
+            children.push(child_field(field, ident)?);
+        }
+    }
+    if !has_raw {
+        return Err(syn::Error::new(
+            input.span(),
+            "expected a `raw: ParsedElement` field",
+        ));
+    }
+
+    let parse = expand_parse(&children);
+    let to_element = expand_to_element(&children);
+    let print_tree = expand_print_tree(&children);
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics crate::matroska::MatroskaElement for #name #type_generics #where_clause {
+            const ID: u64 = #id;
+
+            fn parse<R: crate::ebml::source::ByteSource>(
+                reader: &mut crate::matroska::MatroskaReader<'_, R>,
+                raw: &crate::ebml::reader::ParsedElement,
+            ) -> Result<Self, crate::matroska::MatroskaParseError> {
+                #parse
+            }
+        }
+
+        impl #impl_generics crate::matroska::ToElement for #name #type_generics #where_clause {
+            fn to_element(&self) -> Result<crate::ebml::element::Element, crate::ebml::primitives::ValueError> {
+                #to_element
+            }
+        }
+
+        impl #impl_generics crate::util::tree_printer::TreePrintable for #name #type_generics #where_clause {
+            fn print_tree(
+                &self,
+                out: &mut String,
+                printer: &mut crate::util::tree_printer::TreePrinter,
+                last: bool,
+                show_bytes: bool,
+            ) -> std::fmt::Result {
+                #print_tree
+            }
+        }
+    })
+}
+
+fn expand_parse(children: &[ChildField]) -> TokenStream2 {
+    let declarations = children.iter().map(|child| {
+        let ident = &child.ident;
+        match child.multiplicity {
+            Multiplicity::Multiple => quote! { let mut #ident = Vec::new(); },
+            Multiplicity::Mandatory | Multiplicity::Default(_) | Multiplicity::Optional => {
+                quote! { let mut #ident = None; }
+            }
+        }
+    });
+
+    let arms = children.iter().map(|child| {
+        let ChildField { ident, id, .. } = child;
+        let value = if child.leaf {
+            quote! { crate::matroska::FieldValue::parse_field(reader, child)? }
+        } else {
+            quote! { reader.parse_element(child)? }
+        };
+        match child.multiplicity {
+            Multiplicity::Multiple => quote! { #id => #ident.push(#value), },
+            Multiplicity::Mandatory | Multiplicity::Default(_) | Multiplicity::Optional => {
+                quote! { #id => #ident = Some(#value), }
+            }
+        }
+    });
+
+    let values = children.iter().map(|child| {
+        let ChildField { ident, id, .. } = child;
+        match &child.multiplicity {
+            Multiplicity::Mandatory => quote! {
+                #ident: #ident.ok_or(crate::matroska::MatroskaParseError::MissingElement(
+                    crate::matroska::element_name(#id),
+                ))?
+            },
+            Multiplicity::Default(Some(default)) => quote! {
+                #ident: crate::matroska::OptionalField::new_or_default(#ident, #default)
+            },
+            Multiplicity::Default(None) => quote! {
+                #ident: crate::matroska::OptionalField::new_or_schema_default(#ident, #id)?
+            },
+            Multiplicity::Optional | Multiplicity::Multiple => quote! { #ident },
+        }
+    });
+
+    quote! {
+        assert!(raw.id == Self::ID, "trying to parse invalid element");
+
+        #(#declarations)*
+
+        for child in raw.children.as_deref().unwrap_or(&[]) {
+            match child.id {
+                #(#arms)*
+                _ => reader.report_unhandled(child)?,
+            }
+        }
+
+        Ok(Self {
+            raw: raw.clone(),
+            #(#values,)*
+        })
+    }
+}
+
+fn expand_to_element(children: &[ChildField]) -> TokenStream2 {
+    let pushes = children.iter().map(|child| {
+        let ident = &child.ident;
+        let push = |value: TokenStream2| {
+            quote! {
+                children.push(((#value).raw.header.start, crate::matroska::ToElement::to_element(#value)?));
+            }
+        };
+        match child.multiplicity {
+            Multiplicity::Mandatory => push(quote! { &self.#ident }),
+            Multiplicity::Default(_) => {
+                let push = push(quote! { field });
+                quote! {
+                    if let crate::matroska::OptionalField::Present(field) = &self.#ident {
+                        #push
+                    }
+                }
+            }
+            Multiplicity::Optional => {
+                let push = push(quote! { child });
+                quote! {
+                    if let Some(child) = &self.#ident {
+                        #push
+                    }
+                }
+            }
+            Multiplicity::Multiple => {
+                let push = push(quote! { child });
+                quote! {
+                    for child in &self.#ident {
+                        #push
+                    }
+                }
+            }
+        }
+    });
+
+    quote! {
+        let mut children = Vec::new();
+        #(#pushes)*
+        // Children are written in the order they were read, not in field order
+        children.sort_by_key(|(position, _)| *position);
+        Ok(crate::ebml::element::Element::Master {
+            id: self.raw.id,
+            children: children.into_iter().map(|(_, child)| child).collect(),
+        })
+    }
+}
+
+fn expand_print_tree(children: &[ChildField]) -> TokenStream2 {
+    let pushes = children.iter().map(|child| {
+        let ident = &child.ident;
+        match child.multiplicity {
+            Multiplicity::Mandatory => quote! { children.push(&self.#ident); },
+            Multiplicity::Default(_) => quote! {
+                if let crate::matroska::OptionalField::Present(field) = &self.#ident {
+                    children.push(field);
+                }
+            },
+            Multiplicity::Optional => quote! {
+                if let Some(child) = &self.#ident {
+                    children.push(child);
+                }
+            },
+            Multiplicity::Multiple => quote! {
+                for child in &self.#ident {
+                    children.push(child);
+                }
+            },
+        }
+    });
+
+    quote! {
+        let mut children: Vec<&dyn crate::util::tree_printer::TreePrintable> = Vec::new();
+        #(#pushes)*
+
+        let name = crate::matroska::element_name(self.raw.id);
+        printer.node(out, last, crate::matroska::printer::element_label(name, &self.raw, show_bytes))?;
+        printer.child_scope(last, |printer| {
+            for (i, child) in children.iter().enumerate() {
+                child.print_tree(out, printer, i == children.len() - 1, show_bytes)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn expand_error(input: &DeriveInput) -> String {
+        expand(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_expand() {
+        let input: DeriveInput = parse_quote! {
+            #[matroska(id = INFO_ID)]
+            struct Info {
+                raw: ParsedElement,
+                #[matroska(id = TIMESTAMP_SCALE_ID)]
+                timestamp_scale: OptionalField<u64>,
+                #[matroska(id = DURATION_ID, default = 0.0)]
+                duration: OptionalField<f64>,
+                #[matroska(id = TITLE_ID)]
+                title: Option<Field<String>>,
+                #[matroska(id = CHAPTER_TRANSLATE_ID)]
+                chapter_translates: Vec<ChapterTranslate>,
+            }
+        };
+        let output = expand(&input).unwrap().to_string();
+        assert!(output.contains("new_or_schema_default (timestamp_scale , TIMESTAMP_SCALE_ID)"));
+        assert!(output.contains("new_or_default (duration , 0.0)"));
+        assert!(output.contains("FieldValue :: parse_field (reader , child)"));
+        assert!(output.contains("chapter_translates . push (reader . parse_element (child) ?)"));
+    }
+
+    #[test]
+    fn test_expand_errors() {
+        let missing_id: DeriveInput = parse_quote! {
+            struct Info {
+                raw: ParsedElement,
+            }
+        };
+        assert_eq!(expand_error(&missing_id), "missing `#[matroska(id = ...)]`");
+
+        let missing_field_id: DeriveInput = parse_quote! {
+            #[matroska(id = INFO_ID)]
+            struct Info {
+                raw: ParsedElement,
+                title: Option<Field<String>>,
+            }
+        };
+        assert_eq!(
+            expand_error(&missing_field_id),
+            "missing `#[matroska(id = ...)]`"
+        );
+
+        let default_without_optional_field: DeriveInput = parse_quote! {
+            #[matroska(id = INFO_ID)]
+            struct Info {
+                raw: ParsedElement,
+                #[matroska(id = TITLE_ID, default = String::new())]
+                title: Option<Field<String>>,
+            }
+        };
+        assert_eq!(
+            expand_error(&default_without_optional_field),
+            "`default` is only supported for `OptionalField`"
+        );
+
+        let missing_raw: DeriveInput = parse_quote! {
+            #[matroska(id = INFO_ID)]
+            struct Info {
+                #[matroska(id = TITLE_ID)]
+                title: Option<Field<String>>,
+            }
+        };
+        assert_eq!(
+            expand_error(&missing_raw),
+            "expected a `raw: ParsedElement` field"
+        );
+
+        let unknown_key: DeriveInput = parse_quote! {
+            #[matroska(id = INFO_ID, name = "Info")]
+            struct Info {
+                raw: ParsedElement,
+            }
+        };
+        assert_eq!(expand_error(&unknown_key), "expected `id` or `default`");
+
+        let tuple_struct: DeriveInput = parse_quote! {
+            #[matroska(id = INFO_ID)]
+            struct Info(ParsedElement);
+        };
+        assert_eq!(expand_error(&tuple_struct), "expected named fields");
+    }
+}
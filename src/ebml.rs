@@ -1,9 +1,40 @@
+#[cfg(feature = "async")]
+pub mod async_reader;
+pub mod canonical;
+pub mod crc;
+pub mod cursor;
 pub mod element;
 pub mod error;
+pub mod owned;
 pub mod primitives;
+pub mod printer;
+pub mod query;
 pub mod reader;
+pub mod schema;
+pub mod source;
+pub mod stream;
+pub mod validate;
 pub mod vint;
+pub mod visitor;
+pub mod void;
+pub mod writer;
 
+#[cfg(feature = "async")]
+pub use async_reader::{AsyncEbmlReader, read_element_async, read_root_async};
+pub use canonical::{NonCanonicalSize, non_canonical_sizes};
+pub use crc::{refresh_crcs, verify_crcs};
+pub use cursor::EbmlCursor;
+pub use owned::{OwnedBody, OwnedElement};
+pub use printer::print_ebml_tree;
+pub use query::Query;
 pub use reader::EbmlReader;
 pub use reader::EbmlSchema;
-pub use reader::{read_element, read_root};
+pub use reader::ReaderLimits;
+pub use reader::{DamagedRange, read_element, read_root, read_root_lenient};
+pub use schema::XmlSchema;
+pub use source::{BufferedSource, ByteSource, MmapSource};
+pub use stream::EbmlStreamReader;
+pub use validate::{ValidationIssue, ValidationIssueKind, validate};
+pub use visitor::{EbmlVisitor, VisitedElement, walk_cursor, walk_tree};
+pub use void::{FreeSpace, free_space};
+pub use writer::EbmlWriter;
@@ -0,0 +1,548 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::io::{self, Read, SeekFrom};
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+
+use crate::ebml::cursor::{check_header, ends_unknown_size};
+use crate::ebml::element::{EbmlId, EbmlSize};
+use crate::ebml::error::{EbmlError, ElementPath, map_eof_error};
+use crate::ebml::reader::{
+    ByteRange, DEFAULT_MAX_ID_LENGTH, DEFAULT_MAX_SIZE_LENGTH, EBML_HEADER_ID, EbmlSchema,
+    ElementHeader, ParsedElement, ReaderLimits, apply_max_length, max_length_elements, validate_id,
+};
+use crate::ebml::source::ByteSource;
+
+// Async counterpart of `EbmlReader`, for inputs such as object-store adapters
+// where blocking reads would stall the runtime's worker threads
+pub struct AsyncEbmlReader<R: AsyncRead + AsyncSeek + Unpin> {
+    reader: R,
+    limits: ReaderLimits,
+    elements_read: usize,
+    // Maximum ID and size lengths, as declared by the EBML header
+    max_id_length: u8,
+    max_size_length: u8,
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> AsyncEbmlReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_limits(reader, ReaderLimits::default())
+    }
+
+    pub fn with_limits(reader: R, limits: ReaderLimits) -> Self {
+        Self {
+            reader,
+            limits,
+            elements_read: 0,
+            max_id_length: DEFAULT_MAX_ID_LENGTH,
+            max_size_length: DEFAULT_MAX_SIZE_LENGTH,
+        }
+    }
+
+    pub fn limits(&self) -> &ReaderLimits {
+        &self.limits
+    }
+
+    // Returns the maximum ID and size lengths in bytes elements are validated against
+    pub fn max_lengths(&self) -> (u8, u8) {
+        (self.max_id_length, self.max_size_length)
+    }
+
+    // Sets the maximum ID and size lengths, see `EbmlReader::set_max_lengths`
+    pub fn set_max_lengths(&mut self, max_id_length: u8, max_size_length: u8) {
+        self.max_id_length = max_id_length;
+        self.max_size_length = max_size_length;
+    }
+
+    pub async fn position(&mut self) -> Result<u64, EbmlError> {
+        self.reader.stream_position().await.map_err(EbmlError::from)
+    }
+
+    async fn seek(&mut self, position: u64) -> Result<(), EbmlError> {
+        self.reader.seek(SeekFrom::Start(position)).await?;
+        Ok(())
+    }
+
+    async fn at_eof(&mut self) -> Result<bool, EbmlError> {
+        let position = self.position().await?;
+        let mut buf = [0u8; 1];
+        if self.reader.read(&mut buf).await? == 0 {
+            return Ok(true);
+        }
+        self.seek(position).await?;
+        Ok(false)
+    }
+
+    // Reads the bytes of a VINT, whose length is given by its first byte
+    async fn read_vint_bytes(&mut self, context: &'static str) -> Result<Vec<u8>, EbmlError> {
+        let first = self
+            .reader
+            .read_u8()
+            .await
+            .map_err(|error| map_eof_error(error, context))?;
+        let length = first.leading_zeros() as usize + 1;
+        if length > 8 {
+            return Err(EbmlError::InvalidVint);
+        }
+        let mut bytes = vec![first; length];
+        self.reader
+            .read_exact(&mut bytes[1..])
+            .await
+            .map_err(|error| map_eof_error(error, context))?;
+        Ok(bytes)
+    }
+
+    // Reads the ID and size of the element at the current position
+    pub(crate) async fn read_header(&mut self) -> Result<ElementHeader, EbmlError> {
+        if self.elements_read >= self.limits.max_elements {
+            return Err(EbmlError::TooManyElements(self.limits.max_elements));
+        }
+        self.elements_read += 1;
+        let header_start = self.position().await?;
+
+        let id_bytes = self.read_vint_bytes("reading element ID").await?;
+        let id = EbmlId::read_from(&mut id_bytes.as_slice())?;
+        validate_id(&id, self.max_id_length, &self.limits)?;
+        let size_bytes = self.read_vint_bytes("reading element size").await?;
+        let size = EbmlSize::read_from(&mut size_bytes.as_slice())?;
+        if size.length > self.max_size_length {
+            return Err(EbmlError::SizeTooLong {
+                length: size.length,
+                max: self.max_size_length,
+            });
+        }
+
+        Ok(ElementHeader {
+            id: id.value,
+            header: ByteRange {
+                start: header_start,
+                length: u64::from(id.length + size.length),
+            },
+            size: size.value,
+        })
+    }
+
+    // Reads the bytes of `range`
+    // Fails with `PayloadTooLarge` for ranges larger than the payload limit
+    pub async fn read_range(&mut self, range: &ByteRange) -> Result<Vec<u8>, EbmlError> {
+        // Sizes not addressable in memory are over any limit as well
+        let num_bytes = usize::try_from(range.length)
+            .ok()
+            .filter(|_| range.length <= self.limits.max_payload_size)
+            .ok_or(EbmlError::PayloadTooLarge {
+                size: range.length,
+                limit: self.limits.max_payload_size,
+            })?;
+        let mut buf = vec![0u8; num_bytes];
+        self.seek(range.start).await?;
+        self.reader
+            .read_exact(&mut buf)
+            .await
+            .map_err(|error| map_eof_error(error, "reading element data"))?;
+        Ok(buf)
+    }
+
+    // Applies the maximum ID and size lengths declared by `header`, an EBML header element
+    async fn apply_ebml_header(&mut self, header: &ParsedElement) -> Result<(), EbmlError> {
+        for child in max_length_elements(header) {
+            let data = self.read_range(&child.data).await?;
+            apply_max_length(
+                (&mut self.max_id_length, &mut self.max_size_length),
+                child.id,
+                data,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// A master element whose children are being read
+struct OpenMaster {
+    element: ParsedElement,
+    level: Option<u8>,
+    // `None` for unknown-size masters
+    end: Option<u64>,
+}
+
+// Element tree under construction, with the masters being read on an explicit stack
+// as async functions cannot recurse without boxing
+#[derive(Default)]
+struct TreeBuilder {
+    stack: Vec<OpenMaster>,
+    elements: Vec<ParsedElement>,
+}
+
+impl TreeBuilder {
+    fn path<S: EbmlSchema + ?Sized>(&self, schema: &S) -> ElementPath {
+        let ids: Vec<u64> = self.stack.iter().map(|master| master.element.id).collect();
+        ElementPath::new(&ids, schema)
+    }
+
+    // Returns the end of the open masters, unknown-size masters are bounded by the closest known end
+    fn end(&self) -> Option<u64> {
+        self.stack.iter().rev().find_map(|master| master.end)
+    }
+
+    // Returns the number of children of the innermost open master, or of root elements
+    fn siblings(&self) -> usize {
+        match self.stack.last() {
+            Some(parent) => parent.element.children.as_ref().map_or(0, Vec::len),
+            None => self.elements.len(),
+        }
+    }
+
+    // Returns whether `header` (`None` at the end of the input or of a known-size master)
+    // ends the innermost open master
+    // An unknown-size master ends at the end of its parent, or at the first element
+    // which cannot be its child
+    fn closes_master<S: EbmlSchema + ?Sized>(
+        &self,
+        header: Option<&ElementHeader>,
+        schema: &S,
+    ) -> bool {
+        match (header, self.stack.last()) {
+            (None, Some(_)) => true,
+            (
+                Some(header),
+                Some(OpenMaster {
+                    level, end: None, ..
+                }),
+            ) => ends_unknown_size(schema, *level, header),
+            _ => false,
+        }
+    }
+
+    fn push(&mut self, element: ParsedElement) {
+        match self.stack.last_mut() {
+            Some(parent) => parent
+                .element
+                .children
+                .get_or_insert_with(Vec::new)
+                .push(element),
+            None => self.elements.push(element),
+        }
+    }
+}
+
+// Async counterpart of `read_element`
+pub async fn read_element_async<S: EbmlSchema + ?Sized, R: AsyncRead + AsyncSeek + Unpin>(
+    reader: &mut AsyncEbmlReader<R>,
+    schema: &S,
+) -> Result<ParsedElement, EbmlError> {
+    read_elements(reader, schema, true)
+        .await?
+        .pop()
+        .ok_or(EbmlError::UnexpectedEof("reading element"))
+}
+
+// Async counterpart of `read_root`
+pub async fn read_root_async<S: EbmlSchema + ?Sized, R: AsyncRead + AsyncSeek + Unpin>(
+    reader: &mut AsyncEbmlReader<R>,
+    schema: &S,
+) -> Result<Vec<ParsedElement>, EbmlError> {
+    read_elements(reader, schema, false).await
+}
+
+// Reads the elements at the current level until its end, or only the next one if `single`
+async fn read_elements<S: EbmlSchema + ?Sized, R: AsyncRead + AsyncSeek + Unpin>(
+    reader: &mut AsyncEbmlReader<R>,
+    schema: &S,
+    single: bool,
+) -> Result<Vec<ParsedElement>, EbmlError> {
+    let mut tree = TreeBuilder::default();
+    // Header read but not yet placed, after it ended an unknown-size master
+    let mut pending: Option<ElementHeader> = None;
+
+    loop {
+        if single && tree.stack.is_empty() && !tree.elements.is_empty() {
+            // Leave the reader before the element which ended an unknown-size master
+            if let Some(header) = pending {
+                reader.seek(header.header.start).await?;
+            }
+            return Ok(tree.elements);
+        }
+        let position = match &pending {
+            Some(header) => header.header.start,
+            None => reader.position().await?,
+        };
+        let end = tree.end();
+
+        let header = match pending.take() {
+            Some(header) => Some(header),
+            None if end.is_some_and(|end| position >= end) => None,
+            None if reader.at_eof().await? => None,
+            None => Some(
+                reader
+                    .read_header()
+                    .await
+                    .map_err(|error| error.at(position, tree.path(schema)))?,
+            ),
+        };
+
+        if tree.closes_master(header.as_ref(), schema)
+            && let Some(mut master) = tree.stack.pop()
+        {
+            match master.end {
+                Some(end) => reader.seek(end).await?,
+                None => master.element.data.length = position - master.element.data.start,
+            }
+            if tree.stack.is_empty() && master.element.id == EBML_HEADER_ID {
+                let start = master.element.header.start;
+                reader
+                    .apply_ebml_header(&master.element)
+                    .await
+                    .map_err(|error| error.at(start, tree.path(schema)))?;
+            }
+            tree.push(master.element);
+            pending = header;
+            continue;
+        }
+        let Some(header) = header else {
+            return Ok(tree.elements);
+        };
+
+        check_header(
+            schema,
+            reader.limits(),
+            &header,
+            end,
+            tree.siblings(),
+            tree.stack.len(),
+        )
+        .map_err(|error| error.at(position, tree.path(schema)))?;
+
+        let is_master = schema.is_master(header.id);
+        let element = ParsedElement {
+            id: header.id,
+            data: ByteRange {
+                start: header.data_start(),
+                length: header.size.unwrap_or(0),
+            },
+            header: header.header.clone(),
+            children: is_master.then(Vec::new),
+            unknown_size: header.size.is_none(),
+        };
+        if is_master {
+            tree.stack.push(OpenMaster {
+                element,
+                level: schema.level(header.id),
+                end: header.data_end(),
+            });
+        } else {
+            if let Some(data_end) = header.data_end() {
+                reader.seek(data_end).await?;
+            }
+            tree.push(element);
+        }
+    }
+}
+
+// Synchronous `ByteSource` over element data read ahead of time from an async reader,
+// so that synchronous parsing code can run on it without blocking
+// Only the prefetched ranges can be read, anything else fails with `UnexpectedEof`
+pub(crate) struct PrefetchedSource {
+    // Data by start position
+    ranges: BTreeMap<u64, Vec<u8>>,
+    position: u64,
+}
+
+impl PrefetchedSource {
+    // Prefetches the data of the non-master elements among `subtrees` and their descendants
+    pub(crate) async fn prefetch<'a, R: AsyncRead + AsyncSeek + Unpin>(
+        reader: &mut AsyncEbmlReader<R>,
+        subtrees: impl IntoIterator<Item = &'a ParsedElement>,
+    ) -> Result<Self, EbmlError> {
+        let mut ranges = BTreeMap::new();
+        let mut queue: Vec<&ParsedElement> = subtrees.into_iter().collect();
+        while let Some(element) = queue.pop() {
+            if let Some(children) = &element.children {
+                queue.extend(children);
+            } else {
+                let data = reader.read_range(&element.data).await?;
+                ranges.insert(element.data.start, data);
+            }
+        }
+        Ok(Self {
+            ranges,
+            position: 0,
+        })
+    }
+
+    // Returns the prefetched bytes from the current position onwards
+    fn remaining(&self) -> &[u8] {
+        self.ranges
+            .range(..=self.position)
+            .next_back()
+            .and_then(|(start, data)| {
+                let offset = usize::try_from(self.position - start).ok()?;
+                data.get(offset..)
+            })
+            .unwrap_or(&[])
+    }
+}
+
+impl Read for PrefetchedSource {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = Read::read(&mut self.remaining(), buf)?;
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+impl ByteSource for PrefetchedSource {
+    fn position(&mut self) -> io::Result<u64> {
+        Ok(self.position)
+    }
+
+    fn seek_to(&mut self, position: u64) -> io::Result<()> {
+        self.position = position;
+        Ok(())
+    }
+
+    fn at_eof(&mut self) -> io::Result<bool> {
+        Ok(self.remaining().is_empty())
+    }
+
+    fn read_range(&mut self, start: u64, length: usize) -> io::Result<Cow<'_, [u8]>> {
+        self.position = start;
+        if self.remaining().len() < length {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "range was not prefetched",
+            ));
+        }
+        self.position = start + length as u64;
+        let (range_start, data) = self
+            .ranges
+            .range(..=start)
+            .next_back()
+            .ok_or(io::ErrorKind::UnexpectedEof)?;
+        let offset = usize::try_from(start - range_start).map_err(io::Error::other)?;
+        Ok(Cow::Borrowed(&data[offset..offset + length]))
+    }
+}
+
+// Runs `future` to completion on the current thread without a runtime,
+// for tests over in-memory inputs, whose reads never return `Pending`
+#[cfg(test)]
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    use std::task::{Context, Poll, Waker};
+
+    let future = std::pin::pin!(future);
+    match future.poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("in-memory input should never be pending"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebml::element::Element;
+    use crate::ebml::reader::{EbmlReader, read_root};
+    use std::io::Cursor;
+
+    struct TestSchema;
+
+    impl EbmlSchema for TestSchema {
+        fn is_master(&self, id: u64) -> bool {
+            matches!(id, 0x1A45_DFA3 | 0x1853_8067 | 0x1549_A966 | 0x1F43_B675)
+        }
+
+        fn level(&self, id: u64) -> Option<u8> {
+            match id {
+                0x1A45_DFA3 | 0x1853_8067 => Some(0),
+                0x1549_A966 | 0x1F43_B675 => Some(1),
+                _ => None,
+            }
+        }
+    }
+
+    // EBML header and a Segment with an unknown size, containing Info and a Cluster
+    fn sample_bytes() -> Vec<u8> {
+        let mut bytes = Element::Master {
+            id: 0x1A45_DFA3,
+            children: vec![Element::Raw {
+                id: 0x4282,
+                data: b"matroska".to_vec(),
+            }],
+        }
+        .to_bytes()
+        .unwrap();
+        bytes.extend([0x18, 0x53, 0x80, 0x67, 0xFF]);
+        for child in [
+            Element::Master {
+                id: 0x1549_A966,
+                children: vec![Element::Raw {
+                    id: 0x4D80,
+                    data: b"mkvedit".to_vec(),
+                }],
+            },
+            Element::Master {
+                id: 0x1F43_B675,
+                children: vec![Element::Raw {
+                    id: 0xE7,
+                    data: vec![0x00],
+                }],
+            },
+        ] {
+            bytes.extend(child.to_bytes().unwrap());
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_read_root_async() {
+        block_on(async {
+            let bytes = sample_bytes();
+            let sync_root = read_root(
+                &mut EbmlReader::new(Cursor::new(bytes.clone())),
+                &TestSchema,
+            )
+            .unwrap();
+
+            let mut reader = AsyncEbmlReader::new(Cursor::new(bytes.clone()));
+            let root = read_root_async(&mut reader, &TestSchema).await.unwrap();
+            assert_eq!(format!("{root:?}"), format!("{sync_root:?}"));
+
+            let mut reader = AsyncEbmlReader::new(Cursor::new(bytes));
+            let header = read_element_async(&mut reader, &TestSchema).await.unwrap();
+            assert_eq!(header.id, 0x1A45_DFA3);
+            let segment = read_element_async(&mut reader, &TestSchema).await.unwrap();
+            assert_eq!(segment.children.unwrap().len(), 2);
+        });
+    }
+
+    #[test]
+    fn test_read_async_errors() {
+        block_on(async {
+            // Cluster overrunning its Segment
+            let bytes = vec![0x18, 0x53, 0x80, 0x67, 0x82, 0x1F, 0x43, 0xB6, 0x75, 0x85];
+            let mut reader = AsyncEbmlReader::new(Cursor::new(bytes));
+            let error = read_root_async(&mut reader, &TestSchema).await.unwrap_err();
+            assert!(matches!(error.kind(), EbmlError::ElementOverrun { .. }));
+            assert_eq!(
+                error.path().unwrap().ids().collect::<Vec<_>>(),
+                [0x1853_8067]
+            );
+        });
+    }
+
+    #[test]
+    fn test_prefetched_source() {
+        block_on(async {
+            let mut reader = AsyncEbmlReader::new(Cursor::new(sample_bytes()));
+            let root = read_root_async(&mut reader, &TestSchema).await.unwrap();
+            let segment = root[1].children.as_ref().unwrap();
+            let source = PrefetchedSource::prefetch(&mut reader, [&root[0], &segment[0]])
+                .await
+                .unwrap();
+
+            let mut reader = EbmlReader::new(source);
+            let info = &segment[0].children.as_ref().unwrap()[0];
+            assert_eq!(reader.read_range(&info.data).unwrap().as_ref(), b"mkvedit");
+            let cluster = &segment[1].children.as_ref().unwrap()[0];
+            assert!(reader.read_range(&cluster.data).is_err());
+        });
+    }
+}
@@ -0,0 +1,82 @@
+use crate::ebml::element::{EbmlId, EbmlSize};
+use crate::ebml::reader::ParsedElement;
+
+// An element whose size is encoded with more bytes than needed
+// This is valid EBML, e.g. writers reserve 8 bytes for master sizes patched in later,
+// but wastes space and can hint at sloppy muxers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonCanonicalSize {
+    pub id: u64,
+    // Start of the element header
+    pub position: u64,
+    pub size_length: u8,
+    // Shortest length the size could be encoded with
+    pub canonical_length: u8,
+}
+
+// Lists the elements in `elements` and all their descendants whose size
+// is not encoded at its shortest length, skipping unknown sizes
+pub fn non_canonical_sizes(elements: &[ParsedElement]) -> Vec<NonCanonicalSize> {
+    let mut report = Vec::new();
+    collect_non_canonical_sizes(elements, &mut report);
+    report
+}
+
+fn collect_non_canonical_sizes(elements: &[ParsedElement], report: &mut Vec<NonCanonicalSize>) {
+    for element in elements {
+        if !element.unknown_size {
+            let id_length = u64::from(EbmlId::new(element.id).length);
+            let size_length = u8::try_from(element.header.length - id_length).unwrap_or(u8::MAX);
+            let canonical_length = EbmlSize::new(element.data.length).length;
+            if size_length > canonical_length {
+                report.push(NonCanonicalSize {
+                    id: element.id,
+                    position: element.header.start,
+                    size_length,
+                    canonical_length,
+                });
+            }
+        }
+        if let Some(children) = &element.children {
+            collect_non_canonical_sizes(children, report);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebml::reader::{EbmlReader, EbmlSchema, read_root};
+    use crate::ebml::writer::EbmlWriter;
+    use std::io::Cursor;
+
+    struct TestSchema;
+
+    impl EbmlSchema for TestSchema {
+        fn is_master(&self, id: u64) -> bool {
+            id == 0x1853_8067
+        }
+    }
+
+    #[test]
+    fn test_non_canonical_sizes() {
+        // The writer reserves 8 bytes for master sizes by default
+        let mut writer = EbmlWriter::new(Cursor::new(Vec::new()));
+        writer.start_master(0x1853_8067).unwrap();
+        writer.write_element(0x4D80, b"mkvedit").unwrap();
+        writer.end_master().unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+
+        let mut reader = EbmlReader::new(Cursor::new(bytes));
+        let root = read_root(&mut reader, &TestSchema).unwrap();
+        assert_eq!(
+            non_canonical_sizes(&root),
+            vec![NonCanonicalSize {
+                id: 0x1853_8067,
+                position: 0,
+                size_length: 8,
+                canonical_length: 1,
+            }]
+        );
+    }
+}
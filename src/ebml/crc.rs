@@ -0,0 +1,230 @@
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use crc32fast::Hasher;
+
+use crate::ebml::error::EbmlError;
+use crate::ebml::reader::{ByteRange, EbmlReader, ParsedElement};
+use crate::ebml::source::ByteSource;
+
+// CRC-32 element, allowed as the first child of any master element
+// Its 4-byte little-endian value covers all data of the parent after the CRC-32 element
+pub const CRC32_ID: u64 = 0xBF;
+
+// Element bytes of a CRC-32 element without its value (ID and 1-byte size of 4)
+pub(crate) const CRC32_HEADER: [u8; 2] = [0xBF, 0x84];
+
+// Number of bytes read at a time when computing the CRC-32 of large elements
+const CHUNK_SIZE: u64 = 64 * 1024;
+
+// A master element whose stored CRC-32 does not match its data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrcMismatch {
+    pub id: u64,
+    // Start of the master element header
+    pub position: u64,
+    // `None` if the CRC-32 element does not have a 4-byte value
+    pub stored: Option<u32>,
+    pub computed: u32,
+}
+
+// Returns the CRC-32 child of `element` and the range of data it covers
+fn crc_child(element: &ParsedElement) -> Option<(&ParsedElement, ByteRange)> {
+    let crc = element.children.as_deref()?.first()?;
+    if crc.id != CRC32_ID {
+        return None;
+    }
+    let start = crc.data.start + crc.data.length;
+    let end = element.data.start + element.data.length;
+    Some((
+        crc,
+        ByteRange {
+            start,
+            length: end.saturating_sub(start),
+        },
+    ))
+}
+
+fn compute_crc<R: ByteSource>(
+    reader: &mut EbmlReader<R>,
+    range: &ByteRange,
+) -> Result<u32, EbmlError> {
+    let mut hasher = Hasher::new();
+    let end = range.start + range.length;
+    let mut start = range.start;
+    while start < end {
+        let length = CHUNK_SIZE.min(end - start);
+        hasher.update(&reader.read_range(&ByteRange { start, length })?);
+        start += length;
+    }
+    Ok(hasher.finalize())
+}
+
+// Verifies the CRC-32 of `element`, if its first child is a CRC-32 element
+// Returns `None` if there is no CRC-32 element or the CRC-32 matches
+pub fn verify_crc<R: ByteSource>(
+    reader: &mut EbmlReader<R>,
+    element: &ParsedElement,
+) -> Result<Option<CrcMismatch>, EbmlError> {
+    let Some((crc, range)) = crc_child(element) else {
+        return Ok(None);
+    };
+    let stored = reader
+        .read_range(&crc.data)?
+        .as_ref()
+        .try_into()
+        .ok()
+        .map(u32::from_le_bytes);
+    let computed = compute_crc(reader, &range)?;
+    if stored == Some(computed) {
+        return Ok(None);
+    }
+    Ok(Some(CrcMismatch {
+        id: element.id,
+        position: element.header.start,
+        stored,
+        computed,
+    }))
+}
+
+// Verifies the CRC-32 of `elements` and all their descendants,
+// returning the elements whose CRC-32 does not match
+pub fn verify_crcs<R: ByteSource>(
+    reader: &mut EbmlReader<R>,
+    elements: &[ParsedElement],
+) -> Result<Vec<CrcMismatch>, EbmlError> {
+    let mut mismatches = Vec::new();
+    for element in elements {
+        mismatches.extend(verify_crc(reader, element)?);
+        if let Some(children) = &element.children {
+            mismatches.extend(verify_crcs(reader, children)?);
+        }
+    }
+    Ok(mismatches)
+}
+
+// Recomputes and overwrites in place the existing CRC-32 elements of the masters with
+// one of `ids` among `elements` and their descendants, e.g. after editing element data in place
+// CRC-32 elements of other masters and those without a 4-byte value are left untouched
+// Returns the number of CRC-32 elements written
+pub fn refresh_crcs<F: Read + Write + Seek>(
+    file: &mut F,
+    elements: &[ParsedElement],
+    ids: &[u64],
+) -> Result<usize, EbmlError> {
+    let mut refreshed = 0;
+    for element in elements {
+        // Descendants first, as their CRC-32 elements are part of the data covered by `element`
+        if let Some(children) = &element.children {
+            refreshed += refresh_crcs(file, children, ids)?;
+        }
+        if !ids.contains(&element.id) {
+            continue;
+        }
+        let Some((crc, range)) = crc_child(element) else {
+            continue;
+        };
+        if crc.data.length != 4 {
+            continue;
+        }
+        let computed = compute_crc(&mut EbmlReader::new(&mut *file), &range)?;
+        file.seek(SeekFrom::Start(crc.data.start))?;
+        file.write_all(&computed.to_le_bytes())?;
+        refreshed += 1;
+    }
+    Ok(refreshed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebml::element::Element;
+    use crate::ebml::reader::{EbmlSchema, read_root};
+    use std::io::Cursor;
+
+    struct TestSchema;
+
+    impl EbmlSchema for TestSchema {
+        fn is_master(&self, id: u64) -> bool {
+            matches!(id, 0x1853_8067 | 0x1549_A966 | 0x1654_AE6B)
+        }
+    }
+
+    // Master `id` with a CRC-32 of its data, "123456789"
+    fn sample_master(id: u64) -> Element {
+        Element::Master {
+            id,
+            children: vec![
+                Element::Raw {
+                    id: CRC32_ID,
+                    data: 0x93D0_681Bu32.to_le_bytes().to_vec(),
+                },
+                Element::Raw {
+                    id: 0x7BA9,
+                    data: b"123456789".to_vec(),
+                },
+            ],
+        }
+    }
+
+    fn sample_bytes() -> Vec<u8> {
+        Element::Master {
+            id: 0x1853_8067,
+            children: vec![sample_master(0x1549_A966)],
+        }
+        .to_bytes()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_verify_crcs() {
+        let bytes = sample_bytes();
+        let mut reader = EbmlReader::new(Cursor::new(bytes.clone()));
+        let root = read_root(&mut reader, &TestSchema).unwrap();
+        assert!(verify_crcs(&mut reader, &root).unwrap().is_empty());
+
+        let mut corrupted = bytes;
+        *corrupted.last_mut().unwrap() = b'0';
+        let mut reader = EbmlReader::new(Cursor::new(corrupted));
+        let root = read_root(&mut reader, &TestSchema).unwrap();
+        let mismatches = verify_crcs(&mut reader, &root).unwrap();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].id, 0x1549_A966);
+        assert_eq!(mismatches[0].position, 5);
+        assert_eq!(mismatches[0].stored, Some(0x93D0_681B));
+    }
+
+    #[test]
+    fn test_refresh_crcs() {
+        let mut bytes = sample_bytes();
+        *bytes.last_mut().unwrap() = b'0';
+        let mut file = Cursor::new(bytes);
+        let root = read_root(&mut EbmlReader::new(&mut file), &TestSchema).unwrap();
+        assert_eq!(refresh_crcs(&mut file, &root, &[0x1549_A966]).unwrap(), 1);
+
+        let mut reader = EbmlReader::new(file);
+        assert!(verify_crcs(&mut reader, &root).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_refresh_crcs_selected() {
+        let mut bytes = Element::Master {
+            id: 0x1853_8067,
+            children: vec![sample_master(0x1549_A966), sample_master(0x1654_AE6B)],
+        }
+        .to_bytes()
+        .unwrap();
+        // Corrupt the last byte of both masters
+        let length = bytes.len();
+        let master_length = sample_master(0x1654_AE6B).to_bytes().unwrap().len();
+        bytes[length - master_length - 1] = b'0';
+        bytes[length - 1] = b'0';
+        let mut file = Cursor::new(bytes);
+        let root = read_root(&mut EbmlReader::new(&mut file), &TestSchema).unwrap();
+        assert_eq!(refresh_crcs(&mut file, &root, &[0x1654_AE6B]).unwrap(), 1);
+
+        let mut reader = EbmlReader::new(file);
+        let mismatches = verify_crcs(&mut reader, &root).unwrap();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].id, 0x1549_A966);
+    }
+}
@@ -0,0 +1,396 @@
+use std::borrow::Cow;
+
+use crate::ebml::error::{EbmlError, ElementPath};
+use crate::ebml::reader::{ByteRange, EbmlReader, EbmlSchema, ElementHeader, ReaderLimits};
+use crate::ebml::source::ByteSource;
+
+// Returns whether `header` ends an unknown-size master at `level`,
+// as per the schema it cannot be one of its children
+pub(crate) fn ends_unknown_size<S: EbmlSchema + ?Sized>(
+    schema: &S,
+    level: Option<u8>,
+    header: &ElementHeader,
+) -> bool {
+    level.is_some_and(|level| {
+        schema
+            .level(header.id)
+            .is_some_and(|next_level| next_level <= level)
+    })
+}
+
+// Checks `header`, read after `siblings` other elements inside `depth` masters ending at `end`,
+// against the reader limits, the end of its parent and the schema
+// Shared by `EbmlCursor`, the async reader and the stream reader,
+// so all accept the same element trees
+pub(crate) fn check_header<S: EbmlSchema + ?Sized>(
+    schema: &S,
+    limits: &ReaderLimits,
+    header: &ElementHeader,
+    end: Option<u64>,
+    siblings: usize,
+    depth: usize,
+) -> Result<(), EbmlError> {
+    if siblings >= limits.max_children {
+        return Err(EbmlError::TooManyChildren(limits.max_children));
+    }
+    if let (Some(end), Some(data_end)) = (end, header.data_end())
+        && data_end > end
+    {
+        return Err(EbmlError::ElementOverrun {
+            id: header.id,
+            position: header.header.start,
+            parent_end: end,
+        });
+    }
+    if schema.is_master(header.id) && depth >= limits.max_depth {
+        return Err(EbmlError::DepthLimitExceeded(limits.max_depth));
+    }
+    check_unknown_size(schema, header)
+}
+
+// Only master elements allowed to by the schema may have an unknown size
+pub(crate) fn check_unknown_size<S: EbmlSchema + ?Sized>(
+    schema: &S,
+    header: &ElementHeader,
+) -> Result<(), EbmlError> {
+    if header.size.is_none()
+        && !(schema.is_master(header.id) && schema.unknown_size_allowed(header.id))
+    {
+        return Err(EbmlError::UnknownSizeNotAllowed(header.id));
+    }
+    Ok(())
+}
+
+// A master element the cursor has entered
+struct Frame {
+    id: u64,
+    level: Option<u8>,
+    // `None` for unknown-size masters, which are bounded by their closest known-size ancestor
+    end: Option<u64>,
+    // Number of children read so far
+    children: usize,
+}
+
+// Lazy cursor over the elements of an `EbmlReader`
+// Elements are only read when the cursor is moved to them,
+// so callers can stop at any point without reading the rest of the input
+//
+// `next_element` moves to the next sibling at the current level,
+// `enter` descends into the current master element, and `leave` skips the
+// rest of the entered master and continues among its siblings
+pub struct EbmlCursor<'a, S: EbmlSchema + ?Sized, R: ByteSource> {
+    reader: &'a mut EbmlReader<R>,
+    schema: &'a S,
+    stack: Vec<Frame>,
+    // Number of root-level elements read so far
+    root_children: usize,
+    current: Option<ElementHeader>,
+}
+
+impl<'a, S: EbmlSchema + ?Sized, R: ByteSource> EbmlCursor<'a, S, R> {
+    // Creates a cursor at the current position of `reader`, at the root level
+    pub fn new(reader: &'a mut EbmlReader<R>, schema: &'a S) -> Self {
+        Self {
+            reader,
+            schema,
+            stack: Vec::new(),
+            root_children: 0,
+            current: None,
+        }
+    }
+
+    pub fn schema(&self) -> &'a S {
+        self.schema
+    }
+
+    // Returns the number of master elements entered
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    // Returns the IDs and schema names of the entered master elements
+    pub fn path(&self) -> ElementPath {
+        let ids: Vec<u64> = self.stack.iter().map(|frame| frame.id).collect();
+        ElementPath::new(&ids, self.schema)
+    }
+
+    // Returns the element the cursor is positioned at, if any
+    pub fn current(&self) -> Option<&ElementHeader> {
+        self.current.as_ref()
+    }
+
+    pub fn position(&mut self) -> Result<u64, EbmlError> {
+        self.reader.position()
+    }
+
+    // Returns the reader, e.g. to read data ranges of previously visited elements
+    pub fn reader(&mut self) -> &mut EbmlReader<R> {
+        self.reader
+    }
+
+    // Returns the end of the entered masters,
+    // unknown-size masters are bounded by the closest known end
+    pub(crate) fn end(&self) -> Option<u64> {
+        self.stack.iter().rev().find_map(|frame| frame.end)
+    }
+
+    // Abandons the masters entered below `depth` and moves to `position`,
+    // used to continue reading after an error
+    pub(crate) fn reset(&mut self, depth: usize, position: u64) -> Result<(), EbmlError> {
+        self.stack.truncate(depth);
+        self.current = None;
+        self.reader.seek(position)
+    }
+
+    // Moves to the next element at the current level, skipping the current element if any
+    // Returns `None` when the entered master (or the input) has no more children
+    pub fn next_element(&mut self) -> Result<Option<ElementHeader>, EbmlError> {
+        if self.current.is_some() {
+            self.skip()?;
+        }
+
+        let position = self.reader.position()?;
+        let end = self.end();
+        if end.is_some_and(|end| position >= end) || self.reader.at_eof()? {
+            return Ok(None);
+        }
+
+        let header = self.reader.read_header()?;
+
+        // An unknown-size master ends at the first element which cannot be its child
+        if let Some(Frame {
+            level, end: None, ..
+        }) = self.stack.last()
+            && ends_unknown_size(self.schema, *level, &header)
+        {
+            self.reader.seek(position)?;
+            return Ok(None);
+        }
+        self.reader.count_element()?;
+
+        let limits = *self.reader.limits();
+        let depth = self.stack.len();
+        let children = match self.stack.last_mut() {
+            Some(frame) => &mut frame.children,
+            None => &mut self.root_children,
+        };
+        check_header(self.schema, &limits, &header, end, *children, depth)?;
+        *children += 1;
+
+        self.current = Some(header.clone());
+        Ok(Some(header))
+    }
+
+    // Descends into the current element, which must be a master element
+    pub fn enter(&mut self) -> Result<(), EbmlError> {
+        let header = self.current.take().ok_or(EbmlError::NoCurrentElement)?;
+        if !self.schema.is_master(header.id) {
+            let id = header.id;
+            self.current = Some(header);
+            return Err(EbmlError::NotAMaster(id));
+        }
+        self.stack.push(Frame {
+            id: header.id,
+            level: self.schema.level(header.id),
+            end: header.data_end(),
+            children: 0,
+        });
+        Ok(())
+    }
+
+    // Skips the remaining children of the entered master element,
+    // positioning the cursor before its next sibling
+    pub fn leave(&mut self) -> Result<(), EbmlError> {
+        let end = self.stack.last().ok_or(EbmlError::NoOpenMaster)?.end;
+        match end {
+            Some(end) => {
+                self.current = None;
+                self.reader.seek(end)?;
+            }
+            // The end of an unknown-size master is only found by reading its children
+            None => while self.next_element()?.is_some() {},
+        }
+        self.stack.pop();
+        Ok(())
+    }
+
+    // Skips the current element without entering it
+    pub fn skip(&mut self) -> Result<(), EbmlError> {
+        let header = self.current.as_ref().ok_or(EbmlError::NoCurrentElement)?;
+        if let Some(end) = header.data_end() {
+            self.current = None;
+            return self.reader.seek(end);
+        }
+        if !self.schema.is_master(header.id) {
+            return Err(EbmlError::UnknownSizeNotAllowed(header.id));
+        }
+        self.enter()?;
+        self.leave()
+    }
+
+    // Reads the data of the current element and moves past it
+    pub fn read_data(&mut self) -> Result<Vec<u8>, EbmlError> {
+        let header = self.current.take().ok_or(EbmlError::NoCurrentElement)?;
+        let Some(size) = header.size else {
+            return Err(EbmlError::UnknownSizeNotAllowed(header.id));
+        };
+        self.reader
+            .read_range(&ByteRange {
+                start: header.data_start(),
+                length: size,
+            })
+            .map(Cow::into_owned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebml::element::Element;
+    use crate::matroska::MatroskaSchema;
+    use std::io::Cursor;
+
+    struct TestSchema;
+
+    impl EbmlSchema for TestSchema {
+        fn is_master(&self, id: u64) -> bool {
+            matches!(id, 0x1A45_DFA3 | 0x1853_8067 | 0x1549_A966)
+        }
+
+        fn level(&self, id: u64) -> Option<u8> {
+            match id {
+                0x1A45_DFA3 | 0x1853_8067 => Some(0),
+                0x1549_A966 => Some(1),
+                _ => None,
+            }
+        }
+    }
+
+    fn sample_bytes() -> Vec<u8> {
+        Element::Root {
+            children: vec![
+                Element::Master {
+                    id: 0x1A45_DFA3,
+                    children: vec![Element::Raw {
+                        id: 0x4282,
+                        data: b"matroska".to_vec(),
+                    }],
+                },
+                Element::Master {
+                    id: 0x1853_8067,
+                    children: vec![
+                        Element::Master {
+                            id: 0x1549_A966,
+                            children: vec![Element::Raw {
+                                id: 0x002A_D7B1,
+                                data: vec![0x0F, 0x42, 0x40],
+                            }],
+                        },
+                        Element::Raw {
+                            id: 0xEC,
+                            data: vec![0; 1000],
+                        },
+                    ],
+                },
+            ],
+        }
+        .to_bytes()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_cursor_navigation() {
+        let mut reader = EbmlReader::new(Cursor::new(sample_bytes()));
+        let mut cursor = EbmlCursor::new(&mut reader, &TestSchema);
+
+        assert_eq!(cursor.next_element().unwrap().unwrap().id, 0x1A45_DFA3);
+        assert_eq!(cursor.next_element().unwrap().unwrap().id, 0x1853_8067);
+        cursor.enter().unwrap();
+        assert_eq!(cursor.depth(), 1);
+        assert_eq!(cursor.next_element().unwrap().unwrap().id, 0x1549_A966);
+        cursor.enter().unwrap();
+
+        let timestamp_scale = cursor.next_element().unwrap().unwrap();
+        assert_eq!(timestamp_scale.id, 0x002A_D7B1);
+        assert!(matches!(cursor.enter(), Err(EbmlError::NotAMaster(_))));
+        assert_eq!(cursor.read_data().unwrap(), vec![0x0F, 0x42, 0x40]);
+        assert!(cursor.next_element().unwrap().is_none());
+        cursor.leave().unwrap();
+
+        assert_eq!(cursor.next_element().unwrap().unwrap().id, 0xEC);
+        assert!(cursor.next_element().unwrap().is_none());
+        cursor.leave().unwrap();
+        assert!(cursor.next_element().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_cursor_leave_early() {
+        let mut reader = EbmlReader::new(Cursor::new(sample_bytes()));
+        let mut cursor = EbmlCursor::new(&mut reader, &TestSchema);
+
+        cursor.next_element().unwrap();
+        cursor.enter().unwrap();
+        cursor.leave().unwrap();
+        let segment = cursor.next_element().unwrap().unwrap();
+        assert_eq!(segment.id, 0x1853_8067);
+        assert!(matches!(cursor.leave(), Err(EbmlError::NoOpenMaster)));
+    }
+
+    #[test]
+    fn test_cursor_element_overrun() {
+        let bytes = vec![
+            0x18, 0x53, 0x80, 0x67, 0x83, // Segment, 3 bytes
+            0xEC, 0x82, 0x00, 0x00, // Void, 2 bytes
+        ];
+        let mut reader = EbmlReader::new(Cursor::new(bytes));
+        let mut cursor = EbmlCursor::new(&mut reader, &TestSchema);
+
+        cursor.next_element().unwrap();
+        cursor.enter().unwrap();
+        assert!(matches!(
+            cursor.next_element(),
+            Err(EbmlError::ElementOverrun {
+                id: 0xEC,
+                position: 5,
+                parent_end: 8,
+            })
+        ));
+    }
+
+    #[test]
+    fn test_cursor_skip_unknown_size() {
+        let bytes = vec![
+            0x18, 0x53, 0x80, 0x67, 0xFF, // Segment, unknown size
+            0x15, 0x49, 0xA9, 0x66, 0xFF, // Info, unknown size
+            0xEC, 0x81, 0x00, // Void
+            0x15, 0x49, 0xA9, 0x66, 0x80, // Info, empty
+            0x1A, 0x45, 0xDF, 0xA3, 0x80, // EBML header, empty
+        ];
+        let mut reader = EbmlReader::new(Cursor::new(bytes));
+        let mut cursor = EbmlCursor::new(&mut reader, &TestSchema);
+
+        cursor.next_element().unwrap();
+        cursor.skip().unwrap();
+        assert_eq!(cursor.position().unwrap(), 18);
+        let header = cursor.next_element().unwrap().unwrap();
+        assert_eq!(header.id, 0x1A45_DFA3);
+        assert!(cursor.next_element().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_cursor_unknown_size_not_allowed() {
+        let bytes = vec![
+            0x18, 0x53, 0x80, 0x67, 0xFF, // Segment, unknown size
+            0x15, 0x49, 0xA9, 0x66, 0xFF, // Info, unknown size
+        ];
+        let mut reader = EbmlReader::new(Cursor::new(bytes));
+        let mut cursor = EbmlCursor::new(&mut reader, &MatroskaSchema);
+
+        cursor.next_element().unwrap();
+        cursor.enter().unwrap();
+        assert!(matches!(
+            cursor.next_element(),
+            Err(EbmlError::UnknownSizeNotAllowed(0x1549_A966))
+        ));
+    }
+}
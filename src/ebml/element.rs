@@ -1,6 +1,6 @@
 use std::io::{self, Read};
 
-use crate::ebml::{error, vint::VariableInt};
+use crate::ebml::{error, vint::VariableInt, void::void_bytes};
 
 fn leading_zeros_u64(value: u64) -> u8 {
     // leading_zeros on u64 always returns a value between 0 and 64
@@ -15,9 +15,10 @@ pub struct EbmlId {
     pub length: u8,
 }
 
-//TODO: Handle unknown sizes (all bits set to 1)
+// Represents an EBML element data size
+// `EbmlSize.value` is `None` for unknown sizes (all VINT_DATA bits set to 1)
 pub struct EbmlSize {
-    pub value: u64,
+    pub value: Option<u64>,
     pub length: u8,
 }
 
@@ -29,20 +30,52 @@ impl EbmlId {
     }
 
     // Creates a new `EbmlId` from a u64 value
-    // Assumes `value` is a valid EBML ID and includes the `VINT_MARKER` bit at correct position
+    // Assumes `value` is a valid EBML ID and includes the `VINT_MARKER` bit at correct position,
+    // see `validate` for untrusted values
     pub fn new(value: u64) -> Self {
-        //TODO: Validate ID length
         let length = Self::length_of(value);
         EbmlId { value, length }
     }
 
+    // Checks that the ID is a well-formed VINT of at most `max_id_length` bytes
+    pub fn validate_length(&self, max_id_length: u8) -> Result<(), error::EbmlError> {
+        if !(1..=8).contains(&self.length) || self.value >> (7 * u32::from(self.length)) != 1 {
+            return Err(error::EbmlError::InvalidId(self.value));
+        }
+        if self.length > max_id_length {
+            return Err(error::EbmlError::IdTooLong {
+                id: self.value,
+                length: self.length,
+                max: max_id_length,
+            });
+        }
+        Ok(())
+    }
+
+    // Checks `validate_length`, and that the ID is not reserved
+    // (VINT_DATA all zeros or all ones) and uses the shortest encoding
+    pub fn validate(&self, max_id_length: u8) -> Result<(), error::EbmlError> {
+        self.validate_length(max_id_length)?;
+        // VINT_MARKER for length n is at bit position 7n, as for sizes
+        let marker = 1u64 << (7 * u32::from(self.length));
+        let data = self.value & (marker - 1);
+        if data == 0 || data == marker - 1 {
+            return Err(error::EbmlError::ReservedId(self.value));
+        }
+        // The data would fit in a shorter VINT where it is not the all-ones value
+        if self.length > 1 && data < (marker >> 7) - 1 {
+            return Err(error::EbmlError::NonCanonicalId(self.value));
+        }
+        Ok(())
+    }
+
     //TODO: Create zero-allocating version
     pub fn to_bytes(&self) -> Vec<u8> {
         self.value.to_be_bytes()[8 - usize::from(self.length)..].to_vec()
     }
 
+    // Reads an ID without validating it, see `validate`
     pub fn read_from<R: Read>(reader: &mut R) -> Result<Self, error::EbmlError> {
-        //TODO: Validate ID length
         let vint = VariableInt::read_from(reader)?;
         Ok(EbmlId {
             value: vint.value,
@@ -51,16 +84,22 @@ impl EbmlId {
     }
 }
 
-//TODO: Handle unknown sizes (all bits set to 1)
 impl EbmlSize {
+    // Returns the all-ones VINT_DATA value for a VINT of length n, which is reserved for unknown sizes
+    fn unknown_value(length: u8) -> u64 {
+        (1 << (7 * u64::from(length))) - 1
+    }
+
     // Returns the number of bytes to represent `value` as a VINT
     // VINT_MARKER for length n is at bit position 8n - n = 7n,
-    // therefore (1 << 7n) - 1 is the maximum value representable with VINT of length n
+    // therefore (1 << 7n) - 2 is the maximum value representable with VINT of length n,
+    // as (1 << 7n) - 1 (all bits set) is reserved for unknown sizes
     fn length_of(value: u64) -> u8 {
         for n in 1..=8 {
             // Calculate maximum value representable with VINT of length n
-            // VINT_MARKER is at bit position 7n and we can represent all bits below that
-            let max_value = (1 << (7 * n)) - 1;
+            // VINT_MARKER is at bit position 7n and we can represent all bits below that,
+            // except the all-ones value
+            let max_value = Self::unknown_value(n) - 1;
             if value <= max_value {
                 return n;
             }
@@ -71,12 +110,41 @@ impl EbmlSize {
 
     pub fn new(value: u64) -> Self {
         let length = Self::length_of(value);
-        EbmlSize { value, length }
+        EbmlSize {
+            value: Some(value),
+            length,
+        }
+    }
+
+    // Creates a size encoded with exactly `length` bytes
+    pub fn with_length(value: u64, length: u8) -> Result<Self, error::EbmlError> {
+        if !(1..=8).contains(&length) || value >= Self::unknown_value(length) {
+            return Err(error::EbmlError::SizeTooLarge { value, length });
+        }
+        Ok(EbmlSize {
+            value: Some(value),
+            length,
+        })
+    }
+
+    // Creates an unknown size encoded with `length` bytes
+    pub fn unknown(length: u8) -> Self {
+        EbmlSize {
+            value: None,
+            length,
+        }
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        self.value.is_none()
     }
 
     //TODO: Create zero-allocating version
     pub fn to_bytes(&self) -> Vec<u8> {
-        let vint_value = self.value | (1 << (7 * self.length));
+        let data = self
+            .value
+            .unwrap_or_else(|| Self::unknown_value(self.length));
+        let vint_value = data | (1 << (7 * self.length));
         vint_value.to_be_bytes()[8 - usize::from(self.length)..].to_vec()
     }
 
@@ -85,8 +153,13 @@ impl EbmlSize {
         // Clear VINT_MARKER bit
         // For length n, the VINT_MARKER is at bit position 8n - n = 7n
         let masked_value = vint.value & !(1 << (7 * vint.length));
+        let value = if masked_value == Self::unknown_value(vint.length) {
+            None
+        } else {
+            Some(masked_value)
+        };
         Ok(EbmlSize {
-            value: masked_value,
+            value,
             length: vint.length,
         })
     }
@@ -96,6 +169,8 @@ pub enum Element {
     Raw { id: u64, data: Vec<u8> },
     Master { id: u64, children: Vec<Element> },
     Root { children: Vec<Element> },
+    // Zero-filled Void element of exactly `length` bytes including its header
+    Void { length: u64 },
 }
 
 impl Element {
@@ -131,6 +206,9 @@ impl Element {
                     buffer.extend(child.to_bytes()?);
                 }
             }
+            Element::Void { length } => {
+                buffer.extend(void_bytes(*length).map_err(io::Error::other)?);
+            }
         }
         Ok(buffer)
     }
@@ -139,6 +217,7 @@ impl Element {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ebml::error::EbmlError;
 
     #[test]
     fn test_leading_zeros_u64() {
@@ -152,11 +231,11 @@ mod tests {
     #[test]
     fn test_ebml_id_length_of() {
         let test_cases = vec![
-            (0x1A45DFA3, 4),
+            (0x1A45_DFA3, 4),
             (0x82, 1),
             (0x4286, 2),
-            (0x228681, 3),
-            (0x12868101, 4),
+            (0x0022_8681, 3),
+            (0x1286_8101, 4),
         ];
         for (value, expected_length) in test_cases {
             let length = EbmlId::length_of(value);
@@ -167,14 +246,16 @@ mod tests {
     #[test]
     fn test_ebml_size_length_of() {
         let test_cases = vec![
-            (0x7F, 1),
-            (0x3FFF, 2),
-            (0x1F_FFFF, 3),
-            (0x0F_FF_FF_FF, 4),
-            (0x07_FF_FF_FF_FF, 5),
-            (0x03_FF_FF_FF_FF_FF, 6),
-            (0x01_FF_FF_FF_FF_FF_FF, 7),
-            (0x00_FF_FF_FF_FF_FF_FF_FF, 8),
+            (0x7E, 1),
+            (0x7F, 2),
+            (0x3FFE, 2),
+            (0x3FFF, 3),
+            (0x1F_FFFE, 3),
+            (0x0F_FF_FF_FE, 4),
+            (0x07_FF_FF_FF_FE, 5),
+            (0x03_FF_FF_FF_FF_FE, 6),
+            (0x01_FF_FF_FF_FF_FF_FE, 7),
+            (0x00_FF_FF_FF_FF_FF_FF_FE, 8),
         ];
         for (value, expected_length) in test_cases {
             let length = EbmlSize::length_of(value);
@@ -184,16 +265,75 @@ mod tests {
 
     #[test]
     fn test_ebml_id_to_bytes() {
-        let id = EbmlId::new(0x1A45DFA3);
+        let id = EbmlId::new(0x1A45_DFA3);
         let bytes = id.to_bytes();
         assert_eq!(bytes, vec![0x1A, 0x45, 0xDF, 0xA3]);
     }
 
     #[test]
     fn test_ebml_size_to_bytes() {
-        let size = EbmlSize::new(0x3FFF);
+        let size = EbmlSize::new(0x3FFE);
         let bytes = size.to_bytes();
-        assert_eq!(bytes, vec![0x7F, 0xFF]);
+        assert_eq!(bytes, vec![0x7F, 0xFE]);
+    }
+
+    #[test]
+    fn test_ebml_size_with_length() {
+        let size = EbmlSize::with_length(0x05, 4).unwrap();
+        assert_eq!(size.to_bytes(), vec![0x10, 0x00, 0x00, 0x05]);
+        assert!(EbmlSize::with_length(0x7F, 1).is_err());
+        assert!(EbmlSize::with_length(0x7E, 1).is_ok());
+        assert!(EbmlSize::with_length(0, 9).is_err());
+    }
+
+    #[test]
+    fn test_ebml_size_unknown_to_bytes() {
+        assert_eq!(EbmlSize::unknown(1).to_bytes(), vec![0xFF]);
+        assert_eq!(
+            EbmlSize::unknown(8).to_bytes(),
+            vec![0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]
+        );
+    }
+
+    #[test]
+    fn test_ebml_id_validate() {
+        let validate = |value| EbmlId::new(value).validate(4);
+        assert!(validate(0x1A45_DFA3).is_ok());
+        assert!(validate(0xEC).is_ok());
+        // 0x7F fits in one byte, but 0xFF is reserved
+        assert!(validate(0x407F).is_ok());
+        assert!(matches!(
+            validate(0x4001),
+            Err(EbmlError::NonCanonicalId(_))
+        ));
+        assert!(matches!(validate(0xFF), Err(EbmlError::ReservedId(_))));
+        assert!(matches!(validate(0x80), Err(EbmlError::ReservedId(_))));
+        assert!(matches!(
+            validate(0x1FFF_FFFF),
+            Err(EbmlError::ReservedId(_))
+        ));
+        assert!(matches!(validate(0x0123), Err(EbmlError::InvalidId(_))));
+        assert!(matches!(
+            validate(0x0008_1234_5678),
+            Err(EbmlError::IdTooLong {
+                length: 5,
+                max: 4,
+                ..
+            })
+        ));
+
+        // Reserved and non-shortest IDs are still well-formed
+        let validate_length = |value| EbmlId::new(value).validate_length(4);
+        assert!(validate_length(0x4001).is_ok());
+        assert!(validate_length(0xFF).is_ok());
+        assert!(matches!(
+            validate_length(0x0123),
+            Err(EbmlError::InvalidId(_))
+        ));
+        assert!(matches!(
+            validate_length(0x0008_1234_5678),
+            Err(EbmlError::IdTooLong { .. })
+        ));
     }
 
     #[test]
@@ -201,16 +341,31 @@ mod tests {
         let data = vec![0x1A, 0x45, 0xDF, 0xA3];
         let mut cursor = std::io::Cursor::new(data);
         let id = EbmlId::read_from(&mut cursor).unwrap();
-        assert_eq!(id.value, 0x1A45DFA3);
+        assert_eq!(id.value, 0x1A45_DFA3);
         assert_eq!(id.length, 4);
     }
 
     #[test]
     fn test_ebml_size_read_from() {
-        let data = vec![0x7F, 0xFF];
+        let data = vec![0x7F, 0xFE];
         let mut cursor = std::io::Cursor::new(data);
         let size = EbmlSize::read_from(&mut cursor).unwrap();
-        assert_eq!(size.value, 0x3FFF);
+        assert_eq!(size.value, Some(0x3FFE));
         assert_eq!(size.length, 2);
     }
+
+    #[test]
+    fn test_ebml_size_read_unknown() {
+        for data in [
+            vec![0xFF],
+            vec![0x7F, 0xFF],
+            vec![0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+        ] {
+            let length = data.len();
+            let mut cursor = std::io::Cursor::new(data);
+            let size = EbmlSize::read_from(&mut cursor).unwrap();
+            assert!(size.is_unknown());
+            assert_eq!(usize::from(size.length), length);
+        }
+    }
 }
@@ -1,6 +1,53 @@
+use std::fmt;
 use std::io;
 use thiserror::Error;
 
+use crate::ebml::reader::EbmlSchema;
+
+// IDs of the elements enclosing an error, from the root down,
+// displayed using schema names where known, e.g. `Segment/Tracks/TrackEntry`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ElementPath {
+    elements: Vec<(u64, Option<String>)>,
+}
+
+impl ElementPath {
+    pub fn new<S: EbmlSchema + ?Sized>(ids: &[u64], schema: &S) -> Self {
+        Self {
+            elements: ids
+                .iter()
+                .map(|&id| (id, schema.name(id).map(str::to_string)))
+                .collect(),
+        }
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = u64> + '_ {
+        self.elements.iter().map(|(id, _)| *id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+}
+
+impl fmt::Display for ElementPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.elements.is_empty() {
+            return write!(f, "root");
+        }
+        for (i, (id, name)) in self.elements.iter().enumerate() {
+            if i > 0 {
+                write!(f, "/")?;
+            }
+            match name {
+                Some(name) => write!(f, "{name}")?,
+                None => write!(f, "{id:#X}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum EbmlError {
     #[error("IO error: {0}")]
@@ -9,8 +56,131 @@ pub enum EbmlError {
     #[error("invalid VINT encoding")]
     InvalidVint,
 
+    #[error("invalid element ID {0:#X}")]
+    InvalidId(u64),
+
+    #[error("element ID {id:#X} is {length} bytes long, exceeding the maximum of {max}")]
+    IdTooLong { id: u64, length: u8, max: u8 },
+
+    #[error("element ID {0:#X} is reserved")]
+    ReservedId(u64),
+
+    #[error("element ID {0:#X} is not encoded at its shortest length")]
+    NonCanonicalId(u64),
+
+    #[error("element size is {length} bytes long, exceeding the maximum of {max}")]
+    SizeTooLong { length: u8, max: u8 },
+
+    #[error("EBML header element {0:#X} declares a maximum length outside 1..=8")]
+    InvalidMaxLength(u64),
+
     #[error("unexpected EOF: {0}")]
     UnexpectedEof(&'static str),
+
+    #[error("unknown size not allowed for non-master element {0:#X}")]
+    UnknownSizeNotAllowed(u64),
+
+    #[error("size {value} cannot be encoded using {length} bytes")]
+    SizeTooLarge { value: u64, length: u8 },
+
+    #[error("cannot seek backwards from {position} to {target} in forward-only reader")]
+    BackwardSeek { position: u64, target: u64 },
+
+    #[error("element {0:#X} is not a master element")]
+    NotAMaster(u64),
+
+    #[error("no current element")]
+    NoCurrentElement,
+
+    #[error("no open master element")]
+    NoOpenMaster,
+
+    #[error("{0} master element(s) not closed")]
+    UnclosedMaster(usize),
+
+    #[error("element {id:#X} at {position} overruns its parent ending at {parent_end}")]
+    ElementOverrun {
+        id: u64,
+        position: u64,
+        parent_end: u64,
+    },
+
+    #[error("cannot write a Void element of {0} bytes")]
+    InvalidVoidLength(u64),
+
+    #[error("not enough free space: {needed} bytes needed, {available} available")]
+    NotEnoughFreeSpace { needed: u64, available: u64 },
+
+    #[error("element payload of {size} bytes exceeds the limit of {limit} bytes")]
+    PayloadTooLarge { size: u64, limit: u64 },
+
+    #[error("element nesting exceeds the depth limit of {0}")]
+    DepthLimitExceeded(usize),
+
+    #[error("master element has more than the limit of {0} children")]
+    TooManyChildren(usize),
+
+    #[error("input has more than the limit of {0} elements")]
+    TooManyElements(usize),
+
+    // Any other error, with the offset and enclosing elements of where it occurred
+    #[error("{source} at offset {offset} in {path}")]
+    At {
+        offset: u64,
+        path: ElementPath,
+        source: Box<EbmlError>,
+    },
+}
+
+impl EbmlError {
+    // Adds the location to the error, unless it already has one from a more specific place
+    #[must_use]
+    pub fn at(self, offset: u64, path: ElementPath) -> Self {
+        match self {
+            EbmlError::At { .. } => self,
+            error => EbmlError::At {
+                offset,
+                path,
+                source: Box::new(error),
+            },
+        }
+    }
+
+    // Returns the error without its location
+    pub fn kind(&self) -> &EbmlError {
+        match self {
+            EbmlError::At { source, .. } => source.kind(),
+            error => error,
+        }
+    }
+
+    // Returns whether the error is a `ReaderLimits` violation, which lenient reading
+    // does not recover from as every following element would exceed the limit again
+    pub fn is_limit_exceeded(&self) -> bool {
+        matches!(
+            self.kind(),
+            EbmlError::PayloadTooLarge { .. }
+                | EbmlError::DepthLimitExceeded(_)
+                | EbmlError::TooManyChildren(_)
+                | EbmlError::TooManyElements(_)
+        )
+    }
+
+    // Returns the absolute byte offset of the error, if known
+    pub fn offset(&self) -> Option<u64> {
+        match self {
+            EbmlError::At { offset, .. } => Some(*offset),
+            _ => None,
+        }
+    }
+
+    // Returns the elements enclosing the error, if known
+    pub fn path(&self) -> Option<&ElementPath> {
+        match self {
+            EbmlError::At { path, .. } => Some(path),
+            _ => None,
+        }
+    }
 }
 
 pub fn map_eof_error(error: io::Error, field: &'static str) -> EbmlError {
@@ -0,0 +1,248 @@
+use std::borrow::Cow;
+use std::io::Write;
+
+use crate::ebml::element::{EbmlId, EbmlSize};
+use crate::ebml::error::EbmlError;
+use crate::ebml::reader::{EbmlReader, ParsedElement};
+use crate::ebml::source::ByteSource;
+
+// Contents of an `OwnedElement`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OwnedBody {
+    Data(Vec<u8>),
+    Children(Vec<OwnedElement>),
+}
+
+// Element with its data in memory, keeping the encoding details of the original element
+// so that serializing an unmodified tree reproduces the input byte for byte
+//
+// IDs keep their original width, as an ID value includes its VINT marker and so encodes its length
+// Sizes keep their original width, and are only widened when the data no longer fits,
+// so modifying an element only moves the bytes after it if its size has to grow
+//
+// Elements unknown to the schema are kept as data, as they are read as non-master elements
+// Ranges skipped by lenient reading are not part of the tree and are not reproduced
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedElement {
+    pub id: u64,
+    // Number of bytes the size is encoded with
+    pub size_length: u8,
+    pub unknown_size: bool,
+    pub body: OwnedBody,
+}
+
+impl OwnedElement {
+    // Creates a non-master element whose size uses the shortest encoding
+    pub fn new_data(id: u64, data: Vec<u8>) -> Self {
+        Self {
+            id,
+            size_length: EbmlSize::new(data.len() as u64).length,
+            unknown_size: false,
+            body: OwnedBody::Data(data),
+        }
+    }
+
+    // Creates a master element whose size uses 1 byte, widened as needed when serialized
+    pub fn new_master(id: u64, children: Vec<OwnedElement>) -> Self {
+        Self {
+            id,
+            size_length: 1,
+            unknown_size: false,
+            body: OwnedBody::Children(children),
+        }
+    }
+
+    // Reads the data of `element` and all its descendants from `reader`
+    pub fn read<R: ByteSource>(
+        reader: &mut EbmlReader<R>,
+        element: &ParsedElement,
+    ) -> Result<Self, EbmlError> {
+        let id_length = u64::from(EbmlId::new(element.id).length);
+        // The header was read with at most 8 bytes for the size
+        let size_length = u8::try_from(element.header.length - id_length).unwrap_or(8);
+        let body = match &element.children {
+            Some(children) => OwnedBody::Children(
+                children
+                    .iter()
+                    .map(|child| Self::read(reader, child))
+                    .collect::<Result<_, _>>()?,
+            ),
+            None => OwnedBody::Data(reader.read_range(&element.data).map(Cow::into_owned)?),
+        };
+        Ok(Self {
+            id: element.id,
+            size_length,
+            unknown_size: element.unknown_size,
+            body,
+        })
+    }
+
+    // Reads `elements`, e.g. the result of `read_root`, into owned elements
+    pub fn read_all<R: ByteSource>(
+        reader: &mut EbmlReader<R>,
+        elements: &[ParsedElement],
+    ) -> Result<Vec<Self>, EbmlError> {
+        elements
+            .iter()
+            .map(|element| Self::read(reader, element))
+            .collect()
+    }
+
+    pub fn data(&self) -> Option<&[u8]> {
+        match &self.body {
+            OwnedBody::Data(data) => Some(data),
+            OwnedBody::Children(_) => None,
+        }
+    }
+
+    pub fn children(&self) -> Option<&[OwnedElement]> {
+        match &self.body {
+            OwnedBody::Data(_) => None,
+            OwnedBody::Children(children) => Some(children),
+        }
+    }
+
+    pub fn children_mut(&mut self) -> Option<&mut Vec<OwnedElement>> {
+        match &mut self.body {
+            OwnedBody::Data(_) => None,
+            OwnedBody::Children(children) => Some(children),
+        }
+    }
+
+    // Replaces the data of a non-master element, keeping its size width if the data fits
+    pub fn set_data(&mut self, data: Vec<u8>) -> Result<(), EbmlError> {
+        match &mut self.body {
+            OwnedBody::Data(old) => {
+                *old = data;
+                Ok(())
+            }
+            OwnedBody::Children(_) => Err(EbmlError::NotAMaster(self.id)),
+        }
+    }
+
+    // Returns the number of data bytes, excluding the header
+    pub fn data_length(&self) -> u64 {
+        match &self.body {
+            OwnedBody::Data(data) => data.len() as u64,
+            OwnedBody::Children(children) => children.iter().map(Self::encoded_length).sum(),
+        }
+    }
+
+    // Returns the number of bytes the element is serialized to, including the header
+    pub fn encoded_length(&self) -> u64 {
+        let data_length = self.data_length();
+        u64::from(EbmlId::new(self.id).length)
+            + u64::from(self.size(data_length).length)
+            + data_length
+    }
+
+    // Returns the size to encode, with the original width if `data_length` fits in it
+    fn size(&self, data_length: u64) -> EbmlSize {
+        if self.unknown_size {
+            return EbmlSize::unknown(self.size_length);
+        }
+        EbmlSize::with_length(data_length, self.size_length)
+            .unwrap_or_else(|_| EbmlSize::new(data_length))
+    }
+
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> Result<(), EbmlError> {
+        writer.write_all(&EbmlId::new(self.id).to_bytes())?;
+        writer.write_all(&self.size(self.data_length()).to_bytes())?;
+        match &self.body {
+            OwnedBody::Data(data) => writer.write_all(data)?,
+            OwnedBody::Children(children) => {
+                for child in children {
+                    child.write_to(writer)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EbmlError> {
+        let mut bytes = Vec::new();
+        self.write_to(&mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebml::reader::{EbmlSchema, read_root};
+    use crate::ebml::writer::EbmlWriter;
+    use std::io::Cursor;
+
+    struct TestSchema;
+
+    impl EbmlSchema for TestSchema {
+        fn is_master(&self, id: u64) -> bool {
+            matches!(id, 0x1853_8067 | 0x1549_A966 | 0x1F43_B675)
+        }
+
+        fn level(&self, id: u64) -> Option<u8> {
+            match id {
+                0x1853_8067 => Some(0),
+                0x1549_A966 | 0x1F43_B675 => Some(1),
+                _ => None,
+            }
+        }
+    }
+
+    // Segment with an unknown size, an Info with an 8-byte size,
+    // a child with a non-canonical 2-byte size, an unknown element and a Cluster
+    fn sample_bytes() -> Vec<u8> {
+        let mut writer = EbmlWriter::new(Cursor::new(Vec::new()));
+        writer.start_master(0x1549_A966).unwrap();
+        writer.write_element(0x4D80, b"mkvedit").unwrap();
+        writer.end_master().unwrap();
+        let info = writer.finish().unwrap().into_inner();
+
+        let mut bytes = vec![0x18, 0x53, 0x80, 0x67, 0xFF]; // Segment, unknown size
+        bytes.extend(info);
+        bytes.extend([0x57, 0x41, 0x40, 0x03, b'a', b'b', b'c']); // WritingApp, 2-byte size
+        bytes.extend([0x5F, 0xFF, 0x81, 0x00]); // Unknown element
+        bytes.extend([0x1F, 0x43, 0xB6, 0x75, 0x83, 0xE7, 0x81, 0x00]); // Cluster
+        bytes
+    }
+
+    fn read_owned(bytes: Vec<u8>) -> Vec<OwnedElement> {
+        let mut reader = EbmlReader::new(Cursor::new(bytes));
+        let root = read_root(&mut reader, &TestSchema).unwrap();
+        OwnedElement::read_all(&mut reader, &root).unwrap()
+    }
+
+    #[test]
+    fn test_owned_round_trip() {
+        let bytes = sample_bytes();
+        let owned = read_owned(bytes.clone());
+        assert_eq!(owned.len(), 1);
+        assert_eq!(owned[0].encoded_length(), bytes.len() as u64);
+        assert_eq!(owned[0].to_bytes().unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_owned_modify_leaf() {
+        let bytes = sample_bytes();
+        let mut owned = read_owned(bytes.clone());
+        let segment = owned[0].children_mut().unwrap();
+        assert_eq!(segment[1].id, 0x5741);
+
+        // Same length, only the data changes
+        segment[1].set_data(b"xyz".to_vec()).unwrap();
+        let modified = owned[0].to_bytes().unwrap();
+        let start = bytes.len() - 15;
+        assert_eq!(modified[..start], bytes[..start]);
+        assert_eq!(&modified[start..start + 3], b"xyz");
+        assert_eq!(modified[start + 3..], bytes[start + 3..]);
+
+        // The 8-byte size of Info still fits when its child grows
+        let info = &mut owned[0].children_mut().unwrap()[0];
+        info.children_mut().unwrap()[0]
+            .set_data(b"mkvedit 2".to_vec())
+            .unwrap();
+        let modified = owned[0].to_bytes().unwrap();
+        assert_eq!(modified.len(), bytes.len() + 2);
+        assert_eq!(read_owned(modified), owned);
+    }
+}
@@ -1,20 +1,127 @@
+use std::fmt;
+
 use thiserror::Error;
 
+use crate::ebml::schema::ElementType;
+
 #[derive(Debug, Error)]
 pub enum ValueError {
     #[error("invalid UTF-8 string")]
     InvalidUTF8,
 
-    #[error("invalid integer length: {0}")]
+    #[error("invalid ASCII string")]
+    InvalidAscii,
+
+    #[error("invalid value length: {0}")]
     InvalidLength(usize),
 }
 
+// Number of seconds between the Unix epoch and the EBML date epoch (2001-01-01T00:00:00 UTC)
+pub const EBML_EPOCH_UNIX_SECONDS: i64 = 978_307_200;
+const EBML_EPOCH_UNIX_NANOSECONDS: i64 = EBML_EPOCH_UNIX_SECONDS * 1_000_000_000;
+
+// Represents an EBML date
+// Stored as nanoseconds since 2001-01-01T00:00:00 UTC
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EbmlDate {
+    pub nanoseconds: i64,
+}
+
+impl EbmlDate {
+    pub fn new(nanoseconds: i64) -> Self {
+        Self { nanoseconds }
+    }
+
+    // Returns `None` if the date is out of the range of EBML dates
+    pub fn from_unix_nanos(unix_nanoseconds: i64) -> Option<Self> {
+        unix_nanoseconds
+            .checked_sub(EBML_EPOCH_UNIX_NANOSECONDS)
+            .map(Self::new)
+    }
+
+    // Returns `None` for dates near the end of the EBML date range, out of range in Unix time
+    pub fn to_unix_nanos(self) -> Option<i64> {
+        self.nanoseconds.checked_add(EBML_EPOCH_UNIX_NANOSECONDS)
+    }
+}
+
+// Displayed as an RFC 3339 UTC timestamp, e.g. `2001-01-01T00:00:01.5Z`,
+// or as nanoseconds for dates out of range in Unix time
+impl fmt::Display for EbmlDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(unix_nanoseconds) = self.to_unix_nanos() else {
+            return write!(f, "{}ns", self.nanoseconds);
+        };
+        let seconds = unix_nanoseconds.div_euclid(1_000_000_000);
+        let fraction = unix_nanoseconds.rem_euclid(1_000_000_000);
+        let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+        let time = seconds.rem_euclid(86_400);
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+            time / 3600,
+            time % 3600 / 60,
+            time % 60
+        )?;
+        if fraction != 0 {
+            write!(f, ".{}", format!("{fraction:09}").trim_end_matches('0'))?;
+        }
+        write!(f, "Z")
+    }
+}
+
+// Converts days since 1970-01-01 to a proleptic Gregorian (year, month, day),
+// see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // Months starting from March, so that the leap day is the last day of the year
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+// Formats binary data as uppercase hex, e.g. `0x0A1B`
+pub struct HexBytes<'a>(pub &'a [u8]);
+
+impl fmt::Display for HexBytes<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x")?;
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02X}"))
+    }
+}
+
+// Removes trailing null padding allowed for String and UTF-8 elements
+fn strip_null_padding(mut bytes: Vec<u8>) -> Vec<u8> {
+    if let Some(end) = bytes.iter().position(|&b| b == 0) {
+        bytes.truncate(end);
+    }
+    bytes
+}
+
+// Parses an EBML String element (printable ASCII, optionally null padded)
 pub fn parse_string(bytes: Vec<u8>) -> Result<String, ValueError> {
+    let bytes = strip_null_padding(bytes);
+    if !bytes.iter().all(|b| (0x20..=0x7E).contains(b)) {
+        return Err(ValueError::InvalidAscii);
+    }
     String::from_utf8(bytes).map_err(|_| ValueError::InvalidUTF8)
 }
 
+// Parses an EBML UTF-8 element (optionally null padded)
+pub fn parse_utf8(bytes: Vec<u8>) -> Result<String, ValueError> {
+    String::from_utf8(strip_null_padding(bytes)).map_err(|_| ValueError::InvalidUTF8)
+}
+
+// Parses a big-endian unsigned integer of 0-8 bytes
+// Empty data is 0, the value of an empty element without a schema default
 pub fn parse_u64(bytes: Vec<u8>) -> Result<u64, ValueError> {
-    //TODO: Handle zero length
     if bytes.len() > 8 {
         return Err(ValueError::InvalidLength(bytes.len()));
     }
@@ -24,3 +131,275 @@ pub fn parse_u64(bytes: Vec<u8>) -> Result<u64, ValueError> {
     }
     Ok(value)
 }
+
+// Parses a big-endian two's complement signed integer of 0-8 bytes
+pub fn parse_i64(bytes: Vec<u8>) -> Result<i64, ValueError> {
+    if bytes.len() > 8 {
+        return Err(ValueError::InvalidLength(bytes.len()));
+    }
+    // Sign-extend from the most significant byte
+    let negative = bytes.first().is_some_and(|byte| byte & 0x80 != 0);
+    let mut value: i64 = if negative { -1 } else { 0 };
+    for byte in bytes {
+        value = (value << 8) | i64::from(byte);
+    }
+    Ok(value)
+}
+
+// Parses a big-endian IEEE 754 float of 0, 4 or 8 bytes
+pub fn parse_f64(bytes: Vec<u8>) -> Result<f64, ValueError> {
+    let length = bytes.len();
+    match length {
+        0 => Ok(0.0),
+        4 => bytes
+            .try_into()
+            .map(|buf| f64::from(f32::from_be_bytes(buf)))
+            .map_err(|_| ValueError::InvalidLength(length)),
+        8 => bytes
+            .try_into()
+            .map(f64::from_be_bytes)
+            .map_err(|_| ValueError::InvalidLength(length)),
+        _ => Err(ValueError::InvalidLength(length)),
+    }
+}
+
+// Parses an EBML date of 0 or 8 bytes
+pub fn parse_date(bytes: Vec<u8>) -> Result<EbmlDate, ValueError> {
+    match bytes.len() {
+        0 | 8 => parse_i64(bytes).map(EbmlDate::new),
+        length => Err(ValueError::InvalidLength(length)),
+    }
+}
+
+pub fn parse_binary(bytes: Vec<u8>) -> Result<Vec<u8>, ValueError> {
+    Ok(bytes)
+}
+
+// Parses a float as written in EBML schema defaults and ranges,
+// either decimal (`1.5`) or C99 hexadecimal (`0x1.f4p+12`)
+pub fn parse_float_literal(literal: &str) -> Option<f64> {
+    let (negative, unsigned) = match literal.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, literal.strip_prefix('+').unwrap_or(literal)),
+    };
+    let Some(hex) = unsigned
+        .strip_prefix("0x")
+        .or_else(|| unsigned.strip_prefix("0X"))
+    else {
+        return literal.parse().ok();
+    };
+
+    let (mantissa, exponent) = hex.split_once(['p', 'P']).unwrap_or((hex, "0"));
+    let exponent: i32 = exponent.parse().ok()?;
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    let mut value = 0.0;
+    for digit in integer.chars() {
+        value = value * 16.0 + f64::from(digit.to_digit(16)?);
+    }
+    let mut scale = 1.0 / 16.0;
+    for digit in fraction.chars() {
+        value += f64::from(digit.to_digit(16)?) * scale;
+        scale /= 16.0;
+    }
+    let value = value * 2f64.powi(exponent);
+    Some(if negative { -value } else { value })
+}
+
+pub fn encode_string(value: &str) -> Result<Vec<u8>, ValueError> {
+    if !value.bytes().all(|b| (0x20..=0x7E).contains(&b)) {
+        return Err(ValueError::InvalidAscii);
+    }
+    Ok(value.as_bytes().to_vec())
+}
+
+pub fn encode_utf8(value: &str) -> Vec<u8> {
+    value.as_bytes().to_vec()
+}
+
+// Encodes `value` using the minimal number of bytes (at least one)
+pub fn encode_u64(value: u64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let skip = usize::try_from(value.leading_zeros() / 8).map_or(0, |skip| skip.min(7));
+    bytes[skip..].to_vec()
+}
+
+// Encodes `value` as two's complement using the minimal number of bytes (at least one)
+pub fn encode_i64(value: i64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    // Redundant leading bytes are those equal to the sign extension of the following byte
+    let mut skip = 0;
+    while skip < 7 {
+        let redundant = match bytes[skip] {
+            0x00 => bytes[skip + 1] & 0x80 == 0,
+            0xFF => bytes[skip + 1] & 0x80 != 0,
+            _ => false,
+        };
+        if !redundant {
+            break;
+        }
+        skip += 1;
+    }
+    bytes[skip..].to_vec()
+}
+
+pub fn encode_f32(value: f32) -> Vec<u8> {
+    value.to_be_bytes().to_vec()
+}
+
+pub fn encode_f64(value: f64) -> Vec<u8> {
+    value.to_be_bytes().to_vec()
+}
+
+// Dates are always encoded using 8 bytes
+pub fn encode_date(value: EbmlDate) -> Vec<u8> {
+    value.nanoseconds.to_be_bytes().to_vec()
+}
+
+pub fn encode_binary(value: &[u8]) -> Vec<u8> {
+    value.to_vec()
+}
+
+// A decoded value of any non-master element type
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Integer(i64),
+    UnsignedInteger(u64),
+    Float(f64),
+    String(String),
+    Date(EbmlDate),
+    Binary(Vec<u8>),
+}
+
+// Parses the data of an element of type `element_type`, `None` for master elements
+pub fn parse_value(element_type: ElementType, bytes: Vec<u8>) -> Result<Option<Value>, ValueError> {
+    let value = match element_type {
+        ElementType::Integer => Value::Integer(parse_i64(bytes)?),
+        ElementType::UnsignedInteger => Value::UnsignedInteger(parse_u64(bytes)?),
+        ElementType::Float => Value::Float(parse_f64(bytes)?),
+        ElementType::String => Value::String(parse_string(bytes)?),
+        ElementType::Utf8 => Value::String(parse_utf8(bytes)?),
+        ElementType::Date => Value::Date(parse_date(bytes)?),
+        ElementType::Binary => Value::Binary(parse_binary(bytes)?),
+        ElementType::Master => return Ok(None),
+    };
+    Ok(Some(value))
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Integer(value) => write!(f, "{value}"),
+            Value::UnsignedInteger(value) => write!(f, "{value}"),
+            Value::Float(value) => write!(f, "{value}"),
+            Value::String(value) => write!(f, "{value:?}"),
+            Value::Date(value) => write!(f, "{value}"),
+            Value::Binary(value) => write!(f, "{}", HexBytes(value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_string() {
+        assert_eq!(parse_string(b"webm".to_vec()).unwrap(), "webm");
+        assert_eq!(parse_string(b"webm\0\0".to_vec()).unwrap(), "webm");
+        assert!(matches!(
+            parse_string("é".as_bytes().to_vec()),
+            Err(ValueError::InvalidAscii)
+        ));
+        assert_eq!(parse_utf8("é\0".as_bytes().to_vec()).unwrap(), "é");
+    }
+
+    #[test]
+    fn test_parse_i64() {
+        let test_cases = vec![
+            (vec![], 0),
+            (vec![0x01], 1),
+            (vec![0xFF], -1),
+            (vec![0x80], -128),
+            (vec![0x00, 0x80], 128),
+            (vec![0xFE, 0x00], -512),
+            (vec![0x80, 0, 0, 0, 0, 0, 0, 0], i64::MIN),
+        ];
+        for (bytes, expected) in test_cases {
+            assert_eq!(parse_i64(bytes.clone()).unwrap(), expected);
+            if !bytes.is_empty() {
+                assert_eq!(encode_i64(expected), bytes);
+            }
+        }
+        assert!(parse_i64(vec![0; 9]).is_err());
+    }
+
+    #[test]
+    fn test_encode_u64() {
+        assert_eq!(encode_u64(0), vec![0x00]);
+        assert_eq!(encode_u64(0xFF), vec![0xFF]);
+        assert_eq!(encode_u64(0x0100), vec![0x01, 0x00]);
+        assert_eq!(encode_u64(u64::MAX), vec![0xFF; 8]);
+        assert_eq!(parse_u64(encode_u64(1_000_000)).unwrap(), 1_000_000);
+    }
+
+    #[test]
+    fn test_parse_f64() {
+        assert!(parse_f64(vec![]).unwrap().abs() < f64::EPSILON);
+        assert!((parse_f64(encode_f32(1.5)).unwrap() - 1.5).abs() < f64::EPSILON);
+        assert!((parse_f64(encode_f64(-48000.25)).unwrap() + 48000.25).abs() < f64::EPSILON);
+        assert!(matches!(
+            parse_f64(vec![0; 2]),
+            Err(ValueError::InvalidLength(2))
+        ));
+    }
+
+    #[test]
+    fn test_parse_float_literal() {
+        assert_eq!(parse_float_literal("0x0p+0"), Some(0.0));
+        assert_eq!(parse_float_literal("0x1p+0"), Some(1.0));
+        assert_eq!(parse_float_literal("0x1.f4p+12"), Some(8000.0));
+        assert_eq!(parse_float_literal("-0x1.8p-1"), Some(-0.75));
+        assert_eq!(parse_float_literal("48000.5"), Some(48000.5));
+        assert_eq!(parse_float_literal("0xp+1"), None);
+        assert_eq!(parse_float_literal("0x1.gp+0"), None);
+    }
+
+    #[test]
+    fn test_parse_date() {
+        let date = EbmlDate::from_unix_nanos(EBML_EPOCH_UNIX_SECONDS * 1_000_000_000 + 5).unwrap();
+        assert_eq!(date.nanoseconds, 5);
+        assert_eq!(date.to_unix_nanos(), Some(EBML_EPOCH_UNIX_NANOSECONDS + 5));
+        assert_eq!(EbmlDate::new(i64::MAX).to_unix_nanos(), None);
+        assert_eq!(EbmlDate::from_unix_nanos(i64::MIN), None);
+        assert_eq!(
+            EbmlDate::new(i64::MIN).to_unix_nanos(),
+            Some(i64::MIN + EBML_EPOCH_UNIX_NANOSECONDS)
+        );
+        assert_eq!(parse_date(encode_date(date)).unwrap(), date);
+        assert_eq!(parse_date(vec![]).unwrap(), EbmlDate::new(0));
+        assert!(parse_date(vec![0; 4]).is_err());
+    }
+
+    #[test]
+    fn test_display_date() {
+        assert_eq!(EbmlDate::new(0).to_string(), "2001-01-01T00:00:00Z");
+        assert_eq!(
+            EbmlDate::new(1_500_000_000).to_string(),
+            "2001-01-01T00:00:01.5Z"
+        );
+        assert_eq!(
+            EbmlDate::new(-1).to_string(),
+            "2000-12-31T23:59:59.999999999Z"
+        );
+        let leap_day = EbmlDate::from_unix_nanos(1_709_210_096 * 1_000_000_000).unwrap();
+        assert_eq!(leap_day.to_string(), "2024-02-29T12:34:56Z");
+        assert_eq!(
+            EbmlDate::from_unix_nanos(0).unwrap().to_string(),
+            "1970-01-01T00:00:00Z"
+        );
+        assert_eq!(EbmlDate::new(i64::MAX).to_string(), "9223372036854775807ns");
+    }
+}
@@ -0,0 +1,137 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::fmt::Write;
+
+use crate::ebml::error::EbmlError;
+use crate::ebml::primitives::parse_value;
+use crate::ebml::reader::{EbmlReader, EbmlSchema, ParsedElement};
+use crate::ebml::schema::ElementType;
+use crate::ebml::source::ByteSource;
+use crate::util::tree_printer::TreePrinter;
+
+// Binary elements longer than this are printed as their length instead of their data
+const MAX_PRINTED_BINARY_LENGTH: u64 = 16;
+
+struct Node {
+    label: String,
+    children: Vec<Node>,
+}
+
+// Prints `elements` and all their descendants as a tree, named and decoded using `schema`
+// Elements unknown to the schema are printed with their ID and data length
+pub fn print_ebml_tree<S: EbmlSchema + ?Sized, R: ByteSource>(
+    reader: &mut EbmlReader<R>,
+    schema: &S,
+    elements: &[ParsedElement],
+    show_bytes: bool,
+) -> Result<String, EbmlError> {
+    let nodes = build_nodes(reader, schema, elements, show_bytes)?;
+    let mut out = String::new();
+    // Writing to a String cannot fail
+    let _ = print_nodes(&mut out, &mut TreePrinter::new(), &nodes);
+    Ok(out)
+}
+
+fn build_nodes<S: EbmlSchema + ?Sized, R: ByteSource>(
+    reader: &mut EbmlReader<R>,
+    schema: &S,
+    elements: &[ParsedElement],
+    show_bytes: bool,
+) -> Result<Vec<Node>, EbmlError> {
+    elements
+        .iter()
+        .map(|element| {
+            let children = match &element.children {
+                Some(children) => build_nodes(reader, schema, children, show_bytes)?,
+                None => Vec::new(),
+            };
+            Ok(Node {
+                label: element_label(reader, schema, element, show_bytes)?,
+                children,
+            })
+        })
+        .collect()
+}
+
+fn element_label<S: EbmlSchema + ?Sized, R: ByteSource>(
+    reader: &mut EbmlReader<R>,
+    schema: &S,
+    element: &ParsedElement,
+    show_bytes: bool,
+) -> Result<String, EbmlError> {
+    let mut label = match schema.name(element.id) {
+        Some(name) => name.to_string(),
+        None => format!("Unknown {:#X}", element.id),
+    };
+    match schema.element_type(element.id) {
+        _ if element.children.is_some() => {}
+        Some(ElementType::Binary) | None if element.data.length > MAX_PRINTED_BINARY_LENGTH => {
+            write!(label, ": {} bytes", element.data.length).unwrap();
+        }
+        element_type => {
+            let data = reader.read_range(&element.data).map(Cow::into_owned)?;
+            match parse_value(element_type.unwrap_or(ElementType::Binary), data) {
+                Ok(Some(value)) => write!(label, ": {value}").unwrap(),
+                Ok(None) => {}
+                Err(error) => write!(label, ": <{error}>").unwrap(),
+            }
+        }
+    }
+    if element.unknown_size {
+        label.push_str(" (unknown size)");
+    }
+    if show_bytes {
+        write!(
+            label,
+            " [bytes {}..{}]",
+            element.header.start,
+            element.data.start + element.data.length
+        )
+        .unwrap();
+    }
+    Ok(label)
+}
+
+fn print_nodes(out: &mut String, printer: &mut TreePrinter, nodes: &[Node]) -> fmt::Result {
+    for (i, node) in nodes.iter().enumerate() {
+        let last = i == nodes.len() - 1;
+        printer.node(out, last, &node.label)?;
+        printer.child_scope(last, |printer| print_nodes(out, printer, &node.children))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebml::reader::read_root;
+    use crate::ebml::writer::EbmlWriter;
+    use crate::matroska::MatroskaSchema;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_print_ebml_tree() {
+        let mut writer = EbmlWriter::new(Cursor::new(Vec::new()));
+        writer.start_master(0x1549_A966).unwrap(); // Info
+        writer
+            .write_element(0x002A_D7B1, &[0x0F, 0x42, 0x40])
+            .unwrap(); // TimestampScale
+        writer.write_element(0x4489, &1.5f64.to_be_bytes()).unwrap(); // Duration
+        writer.write_element(0x4D80, b"mkvedit").unwrap(); // MuxingApp
+        writer.write_element(0x5FFF, &[0xAB]).unwrap(); // Unknown
+        writer.end_master().unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+
+        let mut reader = EbmlReader::new(Cursor::new(bytes));
+        let root = read_root(&mut reader, &MatroskaSchema).unwrap();
+        let tree = print_ebml_tree(&mut reader, &MatroskaSchema, &root, false).unwrap();
+        assert_eq!(
+            tree,
+            "└── Info\n    \
+             ├── TimestampScale: 1000000\n    \
+             ├── Duration: 1.5\n    \
+             ├── MuxingApp: \"mkvedit\"\n    \
+             └── Unknown 0x5FFF: 0xAB\n"
+        );
+    }
+}
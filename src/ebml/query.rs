@@ -0,0 +1,398 @@
+use thiserror::Error;
+
+use crate::ebml::error::EbmlError;
+use crate::ebml::primitives::{
+    EbmlDate, Value, parse_binary, parse_date, parse_f64, parse_i64, parse_u64, parse_utf8,
+};
+use crate::ebml::reader::{EbmlReader, EbmlSchema, ParsedElement};
+use crate::ebml::schema::ElementType;
+use crate::ebml::source::ByteSource;
+
+#[derive(Error, Debug)]
+pub enum QueryError {
+    #[error("invalid query syntax: {0}")]
+    InvalidSyntax(String),
+
+    #[error("unknown element: {0}")]
+    UnknownElement(String),
+
+    #[error("invalid value {value:?} for element {element}")]
+    InvalidValue { element: String, value: String },
+}
+
+// Matches children with the given ID and value
+#[derive(Debug, Clone)]
+struct Filter {
+    id: u64,
+    value: Value,
+}
+
+#[derive(Debug, Clone)]
+struct Step {
+    // `None` matches any element
+    id: Option<u64>,
+    filters: Vec<Filter>,
+}
+
+// Path query over `ParsedElement` trees, e.g. `Segment/Tracks/TrackEntry[TrackType=2]/Language`
+//
+// Each step matches children of the elements matched by the previous step,
+// the first step matches the elements passed to `find_all`
+// A step is an element name, a hexadecimal ID such as `0x1654AE6B`, or `*` for any element,
+// optionally followed by filters `[Name=value]` requiring a child with that value
+//
+// Queries are either parsed from a string using a schema, or built step by step
+#[derive(Debug, Clone, Default)]
+pub struct Query {
+    steps: Vec<Step>,
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Adds a step matching elements with `id`
+    #[must_use]
+    pub fn child(mut self, id: u64) -> Self {
+        self.steps.push(Step {
+            id: Some(id),
+            filters: Vec::new(),
+        });
+        self
+    }
+
+    // Adds a step matching any element
+    #[must_use]
+    pub fn any(mut self) -> Self {
+        self.steps.push(Step {
+            id: None,
+            filters: Vec::new(),
+        });
+        self
+    }
+
+    // Restricts the last step to elements with a child `id` with the given value
+    // Does nothing if no step has been added
+    #[must_use]
+    pub fn with_child_value(mut self, id: u64, value: Value) -> Self {
+        if let Some(step) = self.steps.last_mut() {
+            step.filters.push(Filter { id, value });
+        }
+        self
+    }
+
+    // Parses a query, resolving element names and value types through `schema`
+    pub fn parse<S: EbmlSchema + ?Sized>(query: &str, schema: &S) -> Result<Self, QueryError> {
+        let mut result = Self::new();
+        for step in split_steps(query)? {
+            let (name, mut filters) = step
+                .split_once('[')
+                .map_or((step, ""), |(name, filters)| (name, filters));
+            result = match name.trim() {
+                "*" => result.any(),
+                name => result.child(resolve_id(name, schema)?),
+            };
+
+            // `filters` is of the form `A=1][B=2]`
+            while !filters.is_empty() {
+                let (filter, rest) = filters
+                    .split_once(']')
+                    .ok_or_else(|| QueryError::InvalidSyntax(step.to_string()))?;
+                filters = rest.strip_prefix('[').unwrap_or(rest);
+                let (name, value) = filter
+                    .split_once('=')
+                    .ok_or_else(|| QueryError::InvalidSyntax(filter.to_string()))?;
+                let (name, value) = (name.trim(), value.trim().trim_matches('"'));
+                let id = resolve_id(name, schema)?;
+                let value =
+                    parse_filter_value(schema.element_type(id), value).ok_or_else(|| {
+                        QueryError::InvalidValue {
+                            element: name.to_string(),
+                            value: value.to_string(),
+                        }
+                    })?;
+                result = result.with_child_value(id, value);
+            }
+        }
+        Ok(result)
+    }
+
+    // Returns all elements in `elements` and their descendants matching the query,
+    // reading child values from `reader` where filters require them
+    pub fn find_all<'e, R: ByteSource>(
+        &self,
+        reader: &mut EbmlReader<R>,
+        elements: &'e [ParsedElement],
+    ) -> Result<Vec<&'e ParsedElement>, EbmlError> {
+        let mut matches = Vec::new();
+        if !self.steps.is_empty() {
+            self.collect(0, reader, elements, &mut matches)?;
+        }
+        Ok(matches)
+    }
+
+    // Returns the first element matching the query
+    pub fn find_first<'e, R: ByteSource>(
+        &self,
+        reader: &mut EbmlReader<R>,
+        elements: &'e [ParsedElement],
+    ) -> Result<Option<&'e ParsedElement>, EbmlError> {
+        // Queries are expected to match few elements, so this does not stop early
+        Ok(self.find_all(reader, elements)?.into_iter().next())
+    }
+
+    fn collect<'e, R: ByteSource>(
+        &self,
+        index: usize,
+        reader: &mut EbmlReader<R>,
+        elements: &'e [ParsedElement],
+        matches: &mut Vec<&'e ParsedElement>,
+    ) -> Result<(), EbmlError> {
+        let step = &self.steps[index];
+        for element in elements {
+            if step.id.is_some_and(|id| id != element.id) || !step.matches(reader, element)? {
+                continue;
+            }
+            if index + 1 == self.steps.len() {
+                matches.push(element);
+            } else if let Some(children) = &element.children {
+                self.collect(index + 1, reader, children, matches)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Step {
+    // Returns whether `element` satisfies all filters of the step
+    fn matches<R: ByteSource>(
+        &self,
+        reader: &mut EbmlReader<R>,
+        element: &ParsedElement,
+    ) -> Result<bool, EbmlError> {
+        let children = element.children.as_deref().unwrap_or(&[]);
+        for filter in &self.filters {
+            let mut found = false;
+            for child in children.iter().filter(|child| child.id == filter.id) {
+                let bytes = reader.read_range(&child.data)?.into_owned();
+                if decode_like(&filter.value, bytes).is_some_and(|value| value == filter.value) {
+                    found = true;
+                    break;
+                }
+            }
+            if !found {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+// Decodes `bytes` as the same type as `expected`, `None` if the bytes are not a valid value
+fn decode_like(expected: &Value, bytes: Vec<u8>) -> Option<Value> {
+    let value = match expected {
+        Value::Integer(_) => Value::Integer(parse_i64(bytes).ok()?),
+        Value::UnsignedInteger(_) => Value::UnsignedInteger(parse_u64(bytes).ok()?),
+        Value::Float(_) => Value::Float(parse_f64(bytes).ok()?),
+        // String elements are ASCII, a subset of UTF-8
+        Value::String(_) => Value::String(parse_utf8(bytes).ok()?),
+        Value::Date(_) => Value::Date(parse_date(bytes).ok()?),
+        Value::Binary(_) => Value::Binary(parse_binary(bytes).ok()?),
+    };
+    Some(value)
+}
+
+// Splits a query into steps at `/`, except inside filters where values may contain `/`
+fn split_steps(query: &str) -> Result<Vec<&str>, QueryError> {
+    let mut steps = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in query.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| QueryError::InvalidSyntax(query.to_string()))?;
+            }
+            '/' if depth == 0 => {
+                steps.push(&query[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    steps.push(&query[start..]);
+
+    if depth != 0 || steps.iter().any(|step| step.trim().is_empty()) {
+        return Err(QueryError::InvalidSyntax(query.to_string()));
+    }
+    Ok(steps)
+}
+
+fn resolve_id<S: EbmlSchema + ?Sized>(name: &str, schema: &S) -> Result<u64, QueryError> {
+    if let Some(hex) = name.strip_prefix("0x") {
+        return u64::from_str_radix(hex, 16)
+            .map_err(|_| QueryError::UnknownElement(name.to_string()));
+    }
+    schema
+        .id(name)
+        .ok_or_else(|| QueryError::UnknownElement(name.to_string()))
+}
+
+// Parses a filter value as `element_type`
+// Without a type, integers are compared as unsigned integers and anything else as strings
+fn parse_filter_value(element_type: Option<ElementType>, value: &str) -> Option<Value> {
+    let parse_u64 = |value: &str| match value.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    };
+    match element_type {
+        Some(ElementType::UnsignedInteger) => parse_u64(value).map(Value::UnsignedInteger),
+        Some(ElementType::Integer) => value.parse().ok().map(Value::Integer),
+        Some(ElementType::Float) => value.parse().ok().map(Value::Float),
+        Some(ElementType::String | ElementType::Utf8) => Some(Value::String(value.to_string())),
+        Some(ElementType::Date) => value.parse().ok().map(|ns| Value::Date(EbmlDate::new(ns))),
+        Some(ElementType::Binary) => {
+            let hex = value.strip_prefix("0x")?;
+            if hex.len() % 2 != 0 {
+                return None;
+            }
+            (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+                .collect::<Option<Vec<u8>>>()
+                .map(Value::Binary)
+        }
+        Some(ElementType::Master) => None,
+        None => Some(
+            parse_u64(value)
+                .map_or_else(|| Value::String(value.to_string()), Value::UnsignedInteger),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebml::element::Element;
+    use crate::ebml::reader::read_root;
+    use std::io::Cursor;
+
+    struct TestSchema;
+
+    impl EbmlSchema for TestSchema {
+        fn is_master(&self, id: u64) -> bool {
+            matches!(id, 0x1853_8067 | 0x1654_AE6B | 0xAE)
+        }
+
+        fn id(&self, name: &str) -> Option<u64> {
+            match name {
+                "Segment" => Some(0x1853_8067),
+                "Tracks" => Some(0x1654_AE6B),
+                "TrackEntry" => Some(0xAE),
+                "TrackType" => Some(0x83),
+                "CodecID" => Some(0x86),
+                "Language" => Some(0x0022_B59C),
+                _ => None,
+            }
+        }
+
+        fn element_type(&self, id: u64) -> Option<ElementType> {
+            match id {
+                0x83 => Some(ElementType::UnsignedInteger),
+                0x86 | 0x0022_B59C => Some(ElementType::String),
+                _ => None,
+            }
+        }
+    }
+
+    fn track(track_type: u8, codec: &str, language: &str) -> Element {
+        Element::Master {
+            id: 0xAE,
+            children: vec![
+                Element::Raw {
+                    id: 0x83,
+                    data: vec![track_type],
+                },
+                Element::Raw {
+                    id: 0x86,
+                    data: codec.as_bytes().to_vec(),
+                },
+                Element::Raw {
+                    id: 0x0022_B59C,
+                    data: language.as_bytes().to_vec(),
+                },
+            ],
+        }
+    }
+
+    fn sample_root() -> (EbmlReader<Cursor<Vec<u8>>>, Vec<ParsedElement>) {
+        let bytes = Element::Master {
+            id: 0x1853_8067,
+            children: vec![Element::Master {
+                id: 0x1654_AE6B,
+                children: vec![
+                    track(1, "V_MPEG4/ISO/AVC", "und"),
+                    track(2, "A_OPUS", "eng"),
+                    track(2, "A_AAC", "ger"),
+                ],
+            }],
+        }
+        .to_bytes()
+        .unwrap();
+        let mut reader = EbmlReader::new(Cursor::new(bytes));
+        let root = read_root(&mut reader, &TestSchema).unwrap();
+        (reader, root)
+    }
+
+    #[test]
+    fn test_query_parse_and_find() {
+        let (mut reader, root) = sample_root();
+
+        let query = Query::parse(
+            "Segment/Tracks/TrackEntry[TrackType=2]/Language",
+            &TestSchema,
+        )
+        .unwrap();
+        let languages = query.find_all(&mut reader, &root).unwrap();
+        assert_eq!(languages.len(), 2);
+        assert_eq!(
+            reader.read_range(&languages[1].data).unwrap().as_ref(),
+            b"ger"
+        );
+
+        let query = Query::parse(
+            "Segment/*/TrackEntry[CodecID=V_MPEG4/ISO/AVC][TrackType=1]",
+            &TestSchema,
+        )
+        .unwrap();
+        let video = query.find_first(&mut reader, &root).unwrap().unwrap();
+        assert_eq!(video.header.start, 10);
+
+        let query = Query::new()
+            .child(0x1853_8067)
+            .child(0x1654_AE6B)
+            .child(0xAE)
+            .with_child_value(0x83, Value::UnsignedInteger(3));
+        assert!(query.find_all(&mut reader, &root).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_query_parse_errors() {
+        assert!(matches!(
+            Query::parse("Segment/Chapters", &TestSchema),
+            Err(QueryError::UnknownElement(_))
+        ));
+        assert!(matches!(
+            Query::parse("Segment//Tracks", &TestSchema),
+            Err(QueryError::InvalidSyntax(_))
+        ));
+        assert!(matches!(
+            Query::parse("Segment/Tracks/TrackEntry[TrackType=video]", &TestSchema),
+            Err(QueryError::InvalidValue { .. })
+        ));
+        assert!(Query::parse("0x18538067/0x1654AE6B", &TestSchema).is_ok());
+    }
+}
@@ -1,62 +1,370 @@
 use core::fmt;
-use std::io::{Read, Seek, SeekFrom};
+use std::borrow::Cow;
+use std::io;
 
+use crate::ebml::cursor::EbmlCursor;
 use crate::ebml::element::{EbmlId, EbmlSize};
 use crate::ebml::error::EbmlError;
+use crate::ebml::primitives::parse_u64;
+use crate::ebml::schema::{ElementDef, ElementType};
+use crate::ebml::source::ByteSource;
 
+// Longest element header, a 4-byte ID and an 8-byte size
+const MAX_HEADER_LENGTH: usize = 12;
+
+// Number of bytes read at a time when scanning for an element to resynchronize on
+const SCAN_CHUNK_SIZE: usize = 64 * 1024;
+
+// Level of the elements lenient reading resynchronizes on after an error,
+// e.g. the top-level elements of a Matroska Segment such as Cluster and Cues
+const RESYNC_LEVEL: u8 = 1;
+
+// EBML header and the children declaring the maximum ID and size lengths
+pub(crate) const EBML_HEADER_ID: u64 = 0x1A45_DFA3;
+const EBML_MAX_ID_LENGTH_ID: u64 = 0x42F2;
+const EBML_MAX_SIZE_LENGTH_ID: u64 = 0x42F3;
+
+// Maximum ID and size lengths of documents whose EBML header does not declare them
+pub(crate) const DEFAULT_MAX_ID_LENGTH: u8 = 4;
+pub(crate) const DEFAULT_MAX_SIZE_LENGTH: u8 = 8;
+
+// Describes the elements of a document type
+// Only `is_master` is required, the other methods fall back to `definition` if it is implemented
 pub trait EbmlSchema {
     //TODO: Rename from master?
-    fn is_master(id: u64) -> bool;
+    fn is_master(&self, id: u64) -> bool;
+
+    // Returns the full schema definition of `id`, with its name, type, path, occurrence and range
+    fn definition(&self, _id: u64) -> Option<&ElementDef> {
+        None
+    }
+
+    // Returns all element definitions of the schema, e.g. to find the mandatory children of a master
+    fn definitions(&self) -> Box<dyn Iterator<Item = &ElementDef> + '_> {
+        Box::new(std::iter::empty())
+    }
+
+    // Returns the level of `id` in the schema (0 for root elements)
+    // Used to determine where unknown-size elements end,
+    // `None` for global or unknown elements, which are treated as valid children anywhere
+    fn level(&self, id: u64) -> Option<u8> {
+        self.definition(id).and_then(ElementDef::level)
+    }
+
+    // Returns the name of `id`, used to describe where errors occurred
+    fn name(&self, id: u64) -> Option<&str> {
+        self.definition(id).map(|element| element.name.as_ref())
+    }
+
+    // Returns the ID of the element called `name`, the inverse of `name`
+    fn id(&self, name: &str) -> Option<u64> {
+        self.definitions()
+            .find(|element| element.name == name)
+            .map(|element| element.id)
+    }
+
+    // Returns the type of `id`, used to decode values of elements without a typed model
+    fn element_type(&self, id: u64) -> Option<ElementType> {
+        self.definition(id).map(|element| element.element_type)
+    }
+
+    // Returns whether `id` may be written with an unknown size
+    // Without a definition, all master elements are allowed to
+    fn unknown_size_allowed(&self, id: u64) -> bool {
+        match self.definition(id) {
+            Some(element) => element.unknown_size_allowed,
+            None => self.is_master(id),
+        }
+    }
+}
+
+// Limits protecting against crafted or corrupt input, e.g. when parsing untrusted files
+// Exceeding a limit fails with a dedicated `EbmlError` variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReaderLimits {
+    // Maximum number of bytes read into memory for a single element
+    pub max_payload_size: u64,
+    // Maximum number of nested master elements
+    pub max_depth: usize,
+    // Maximum number of children of a single master element (or at the root level)
+    pub max_children: usize,
+    // Maximum number of element headers read in total
+    pub max_elements: usize,
+    // Whether reserved IDs and IDs not encoded at their shortest length are rejected,
+    // rather than read as any other unknown element
+    pub strict_ids: bool,
+}
+
+impl ReaderLimits {
+    pub const fn unlimited() -> Self {
+        Self {
+            max_payload_size: u64::MAX,
+            max_depth: usize::MAX,
+            max_children: usize::MAX,
+            max_elements: usize::MAX,
+            strict_ids: false,
+        }
+    }
 }
 
-pub struct EbmlReader<R: Read + Seek> {
+impl Default for ReaderLimits {
+    // Generous enough for any sane Matroska file, but bounded in memory and stack use
+    fn default() -> Self {
+        Self {
+            max_payload_size: 256 * 1024 * 1024,
+            max_depth: 64,
+            max_children: 1_000_000,
+            max_elements: 10_000_000,
+            strict_ids: false,
+        }
+    }
+}
+
+pub struct EbmlReader<R: ByteSource> {
     reader: R,
+    limits: ReaderLimits,
+    elements_read: usize,
+    // Maximum ID and size lengths, as declared by the EBML header
+    max_id_length: u8,
+    max_size_length: u8,
 }
 
-impl<R: Read + Seek> EbmlReader<R> {
+impl<R: ByteSource> EbmlReader<R> {
     pub fn new(reader: R) -> Self {
-        Self { reader }
+        Self::with_limits(reader, ReaderLimits::default())
+    }
+
+    pub fn with_limits(reader: R, limits: ReaderLimits) -> Self {
+        Self {
+            reader,
+            limits,
+            elements_read: 0,
+            max_id_length: DEFAULT_MAX_ID_LENGTH,
+            max_size_length: DEFAULT_MAX_SIZE_LENGTH,
+        }
+    }
+
+    pub fn limits(&self) -> &ReaderLimits {
+        &self.limits
+    }
+
+    // Returns the maximum ID and size lengths in bytes elements are validated against
+    pub fn max_lengths(&self) -> (u8, u8) {
+        (self.max_id_length, self.max_size_length)
+    }
+
+    // Sets the maximum ID and size lengths, as declared by `EBMLMaxIDLength`
+    // and `EBMLMaxSizeLength` in the EBML header
+    // `read_root` does this itself when it reads an EBML header
+    pub fn set_max_lengths(&mut self, max_id_length: u8, max_size_length: u8) {
+        self.max_id_length = max_id_length;
+        self.max_size_length = max_size_length;
     }
 
     fn read_id(&mut self) -> Result<EbmlId, EbmlError> {
-        EbmlId::read_from(&mut self.reader)
+        let id = EbmlId::read_from(&mut self.reader)?;
+        validate_id(&id, self.max_id_length, &self.limits)?;
+        Ok(id)
     }
 
     fn read_size(&mut self) -> Result<EbmlSize, EbmlError> {
-        EbmlSize::read_from(&mut self.reader)
+        let size = EbmlSize::read_from(&mut self.reader)?;
+        if size.length > self.max_size_length {
+            return Err(EbmlError::SizeTooLong {
+                length: size.length,
+                max: self.max_size_length,
+            });
+        }
+        Ok(size)
     }
 
-    fn position(&mut self) -> Result<u64, EbmlError> {
-        self.reader.stream_position().map_err(EbmlError::from)
+    // Applies the maximum ID and size lengths declared by `header`, an EBML header element
+    // Missing values leave the current maximum as is
+    fn apply_ebml_header(&mut self, header: &ParsedElement) -> Result<(), EbmlError> {
+        for child in max_length_elements(header) {
+            let data = self.read_range(&child.data)?.into_owned();
+            apply_max_length(
+                (&mut self.max_id_length, &mut self.max_size_length),
+                child.id,
+                data,
+            )?;
+        }
+        Ok(())
     }
 
-    fn seek(&mut self, pos: u64) -> Result<(), EbmlError> {
-        self.reader
-            .seek(SeekFrom::Start(pos))
-            .map(|_| ())
-            .map_err(EbmlError::from)
+    // Counts an element read against `max_elements`
+    pub(crate) fn count_element(&mut self) -> Result<(), EbmlError> {
+        if self.elements_read >= self.limits.max_elements {
+            return Err(EbmlError::TooManyElements(self.limits.max_elements));
+        }
+        self.elements_read += 1;
+        Ok(())
+    }
+
+    // Reads the ID and size of the element at the current position
+    // The element is not counted, as the header may be read again after seeking back
+    pub(crate) fn read_header(&mut self) -> Result<ElementHeader, EbmlError> {
+        let header_start = self.position()?;
+        let id = self.read_id()?;
+        let size = self.read_size()?;
+        Ok(ElementHeader {
+            id: id.value,
+            header: ByteRange {
+                start: header_start,
+                length: u64::from(id.length + size.length),
+            },
+            size: size.value,
+        })
+    }
+
+    pub(crate) fn position(&mut self) -> Result<u64, EbmlError> {
+        self.reader.position().map_err(EbmlError::from)
+    }
+
+    pub(crate) fn seek(&mut self, pos: u64) -> Result<(), EbmlError> {
+        self.reader.seek_to(pos).map_err(EbmlError::from)
+    }
+
+    pub(crate) fn at_eof(&mut self) -> Result<bool, EbmlError> {
+        self.reader.at_eof().map_err(EbmlError::from)
+    }
+
+    // Scans forward byte by byte from `start` for an element header accepted by `accept`,
+    // returning its position, or the position where scanning stopped (`end` or EOF)
+    pub(crate) fn scan_for(
+        &mut self,
+        start: u64,
+        end: Option<u64>,
+        mut accept: impl FnMut(&ElementHeader) -> bool,
+    ) -> Result<u64, EbmlError> {
+        let mut window = Vec::new();
+        let mut offset = 0;
+        let mut position = start;
+        let mut eof = false;
+
+        while end.is_none_or(|end| position < end) {
+            if window.len() - offset < MAX_HEADER_LENGTH && !eof {
+                window.drain(..offset);
+                offset = 0;
+                self.seek(position + window.len() as u64)?;
+                let filled = window.len();
+                window.resize(filled + SCAN_CHUNK_SIZE, 0);
+                let n = self.read_fully(&mut window[filled..])?;
+                window.truncate(filled + n);
+                eof = n < SCAN_CHUNK_SIZE;
+            }
+            if offset == window.len() {
+                break;
+            }
+
+            let mut bytes = &window[offset..];
+            if let (Ok(id), Ok(size)) = (
+                EbmlId::read_from(&mut bytes),
+                EbmlSize::read_from(&mut bytes),
+            ) {
+                let header = ElementHeader {
+                    id: id.value,
+                    header: ByteRange {
+                        start: position,
+                        length: u64::from(id.length + size.length),
+                    },
+                    size: size.value,
+                };
+                if accept(&header) {
+                    return Ok(position);
+                }
+            }
+            offset += 1;
+            position += 1;
+        }
+        Ok(position)
     }
 
-    fn at_eof(&mut self) -> Result<bool, EbmlError> {
-        let pos = self.position()?;
-        let mut buf = [0u8; 1];
-        match self.reader.read(&mut buf) {
-            Ok(0) => Ok(true),
-            Ok(_) => {
-                self.seek(pos)?;
-                Ok(false)
+    // Reads until `buf` is full or EOF is reached, returning the number of bytes read
+    fn read_fully(&mut self, buf: &mut [u8]) -> Result<usize, EbmlError> {
+        let mut filled = 0;
+        while filled < buf.len() {
+            match self.reader.read(&mut buf[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(EbmlError::from(e)),
             }
-            Err(e) => Err(EbmlError::from(e)),
         }
+        Ok(filled)
     }
 
-    pub fn read_range(&mut self, range: &ByteRange) -> Result<Vec<u8>, EbmlError> {
-        //TODO: Maybe more appropriate error than InvalidVint
-        let num_bytes = usize::try_from(range.length).map_err(|_| EbmlError::InvalidVint)?;
-        let mut buf = vec![0u8; num_bytes];
-        self.seek(range.start)?;
-        self.reader.read_exact(&mut buf)?;
-        Ok(buf)
+    // Reads the bytes of `range`, borrowed from the source when it supports it
+    // Fails with `PayloadTooLarge` for ranges larger than the payload limit
+    pub fn read_range(&mut self, range: &ByteRange) -> Result<Cow<'_, [u8]>, EbmlError> {
+        // Sizes not addressable in memory are over any limit as well
+        let num_bytes = usize::try_from(range.length)
+            .ok()
+            .filter(|_| range.length <= self.limits.max_payload_size)
+            .ok_or(EbmlError::PayloadTooLarge {
+                size: range.length,
+                limit: self.limits.max_payload_size,
+            })?;
+        self.reader
+            .read_range(range.start, num_bytes)
+            .map_err(EbmlError::from)
+    }
+}
+
+// Returns the children of `header`, an EBML header element,
+// declaring the maximum ID and size lengths
+pub(crate) fn max_length_elements(header: &ParsedElement) -> impl Iterator<Item = &ParsedElement> {
+    header
+        .children
+        .as_deref()
+        .unwrap_or(&[])
+        .iter()
+        .filter(|child| is_max_length_id(child.id))
+}
+
+// Returns whether `id` is `EBMLMaxIDLength` or `EBMLMaxSizeLength`
+pub(crate) fn is_max_length_id(id: u64) -> bool {
+    id == EBML_MAX_ID_LENGTH_ID || id == EBML_MAX_SIZE_LENGTH_ID
+}
+
+// Applies `data`, the value of the `max_length_elements` child `id`,
+// to the maximum ID and size lengths
+// Fails with `InvalidMaxLength` for values outside 1..=8, which would misread the input
+pub(crate) fn apply_max_length(
+    max_lengths: (&mut u8, &mut u8),
+    id: u64,
+    data: Vec<u8>,
+) -> Result<(), EbmlError> {
+    let (max_id_length, max_size_length) = max_lengths;
+    let (max_length, default) = if id == EBML_MAX_ID_LENGTH_ID {
+        (max_id_length, DEFAULT_MAX_ID_LENGTH)
+    } else {
+        (max_size_length, DEFAULT_MAX_SIZE_LENGTH)
+    };
+    // Empty elements take their default value
+    if data.is_empty() {
+        *max_length = default;
+        return Ok(());
+    }
+    *max_length = parse_u64(data)
+        .ok()
+        .and_then(|value| u8::try_from(value).ok())
+        .filter(|length| (1..=8).contains(length))
+        .ok_or(EbmlError::InvalidMaxLength(id))?;
+    Ok(())
+}
+
+// Validates an ID read from the input, rejecting reserved and non-shortest IDs
+// only with `strict_ids`, as they are otherwise readable
+pub(crate) fn validate_id(
+    id: &EbmlId,
+    max_id_length: u8,
+    limits: &ReaderLimits,
+) -> Result<(), EbmlError> {
+    if limits.strict_ids {
+        id.validate(max_id_length)
+    } else {
+        id.validate_length(max_id_length)
     }
 }
 
@@ -67,12 +375,35 @@ pub struct ByteRange {
     pub length: u64,
 }
 
+// Header of an element, available before its data has been read
+#[derive(Debug, Clone)]
+pub struct ElementHeader {
+    pub id: u64,
+    pub header: ByteRange,
+    // `None` for unknown sizes
+    pub size: Option<u64>,
+}
+
+impl ElementHeader {
+    pub fn data_start(&self) -> u64 {
+        self.header.start + self.header.length
+    }
+
+    // Returns the end of the element data, `None` for unknown sizes
+    pub fn data_end(&self) -> Option<u64> {
+        self.size.map(|size| self.data_start() + size)
+    }
+}
+
 #[derive(Clone)]
 pub struct ParsedElement {
     pub id: u64,
     pub header: ByteRange,
     pub data: ByteRange,
     pub children: Option<Vec<ParsedElement>>,
+    // Whether the element was encoded with an unknown size
+    // `data.length` is then the length determined while parsing the children
+    pub unknown_size: bool,
 }
 
 impl fmt::Debug for ParsedElement {
@@ -82,60 +413,432 @@ impl fmt::Debug for ParsedElement {
             .field("header", &self.header)
             .field("data", &self.data)
             .field("children", &self.children)
+            .field("unknown_size", &self.unknown_size)
             .finish()
     }
 }
 
-pub fn read_element<S: EbmlSchema, R: Read + Seek>(
+pub fn read_element<S: EbmlSchema + ?Sized, R: ByteSource>(
     r: &mut EbmlReader<R>,
+    schema: &S,
 ) -> Result<ParsedElement, EbmlError> {
-    let header_start = r.position()?;
-    let id_vint = r.read_id()?;
-    let size_vint = r.read_size()?;
-    let header_length = u64::from(id_vint.length + size_vint.length);
-
-    let header = ByteRange {
-        start: header_start,
-        length: header_length,
-    };
+    let mut cursor = EbmlCursor::new(r, schema);
+    let start = cursor.position()?;
+    cursor
+        .next_element()
+        .and_then(|header| header.ok_or(EbmlError::UnexpectedEof("reading element")))
+        .and_then(|header| read_current(&mut cursor, header, None))
+        .map_err(|error| error.at(start, cursor.path()))
+}
 
-    let data_start = r.position()?;
-    let data_length = size_vint.value;
+pub fn read_root<S: EbmlSchema + ?Sized, R: ByteSource>(
+    r: &mut EbmlReader<R>,
+    schema: &S,
+) -> Result<Vec<ParsedElement>, EbmlError> {
+    let mut cursor = EbmlCursor::new(r, schema);
+    read_children(&mut cursor, None, None)
+}
 
-    let data = ByteRange {
-        start: data_start,
-        length: data_length,
-    };
+// A byte range skipped by `read_root_lenient`, and the error which caused it
+#[derive(Debug)]
+pub struct DamagedRange {
+    pub range: ByteRange,
+    pub error: EbmlError,
+}
 
-    if S::is_master(id_vint.value) {
-        let mut children = Vec::new();
-        let end = data.start + data.length;
+// Same as `read_root`, but recovers from errors inside root-level masters (e.g. a Segment)
+// On an error, the level 1 element containing it is dropped and recorded as damaged,
+// and reading continues at the next level 1 element found by scanning forward
+// Errors outside level 1 elements, e.g. in the EBML header, and exceeded `ReaderLimits`
+// are still returned
+pub fn read_root_lenient<S: EbmlSchema + ?Sized, R: ByteSource>(
+    r: &mut EbmlReader<R>,
+    schema: &S,
+) -> Result<(Vec<ParsedElement>, Vec<DamagedRange>), EbmlError> {
+    let mut damaged = Vec::new();
+    let mut cursor = EbmlCursor::new(r, schema);
+    let elements = read_children(&mut cursor, None, Some(&mut damaged))?;
+    Ok((elements, damaged))
+}
 
-        while r.position()? < end {
-            children.push(read_element::<S, R>(r)?);
+// Eagerly reads all remaining elements at the current cursor level
+// With `damaged`, errors in the children of a level 0 master are recovered from
+fn read_children<S: EbmlSchema + ?Sized, R: ByteSource>(
+    cursor: &mut EbmlCursor<S, R>,
+    level: Option<u8>,
+    mut damaged: Option<&mut Vec<DamagedRange>>,
+) -> Result<Vec<ParsedElement>, EbmlError> {
+    let recover = level == Some(RESYNC_LEVEL - 1);
+    let depth = cursor.depth();
+    let mut elements = Vec::new();
+    loop {
+        let start = cursor.position()?;
+        let result = match cursor.next_element() {
+            Ok(Some(header)) => read_current(cursor, header, damaged.as_deref_mut()).map(Some),
+            Ok(None) => Ok(None),
+            Err(error) => Err(error),
+        }
+        .map_err(|error| error.at(start, cursor.path()));
+        match (result, damaged.as_deref_mut()) {
+            (Ok(Some(element)), _) => {
+                if depth == 0 && element.id == EBML_HEADER_ID {
+                    cursor
+                        .reader()
+                        .apply_ebml_header(&element)
+                        .map_err(|error| error.at(start, cursor.path()))?;
+                }
+                elements.push(element);
+            }
+            (Ok(None), _) => break,
+            (Err(error), Some(damaged)) if recover && !error.is_limit_exceeded() => {
+                let end = cursor.end();
+                let schema = cursor.schema();
+                let resume = cursor.reader().scan_for(start + 1, end, |header| {
+                    schema.level(header.id) == Some(RESYNC_LEVEL)
+                        && header
+                            .data_end()
+                            .is_none_or(|data_end| end.is_none_or(|end| data_end <= end))
+                })?;
+                damaged.push(DamagedRange {
+                    range: ByteRange {
+                        start,
+                        length: resume - start,
+                    },
+                    error,
+                });
+                cursor.reset(depth, resume)?;
+            }
+            (Err(error), _) => return Err(error),
         }
+    }
+    Ok(elements)
+}
+
+// Eagerly reads the element the cursor is currently positioned at
+fn read_current<S: EbmlSchema + ?Sized, R: ByteSource>(
+    cursor: &mut EbmlCursor<S, R>,
+    header: ElementHeader,
+    damaged: Option<&mut Vec<DamagedRange>>,
+) -> Result<ParsedElement, EbmlError> {
+    let data_start = header.data_start();
+
+    if !cursor.schema().is_master(header.id) {
+        let length = header
+            .size
+            .ok_or(EbmlError::UnknownSizeNotAllowed(header.id))?;
+        cursor.skip()?;
         return Ok(ParsedElement {
-            id: id_vint.value,
-            header,
-            data,
-            children: Some(children),
+            id: header.id,
+            header: header.header,
+            data: ByteRange {
+                start: data_start,
+                length,
+            },
+            children: None,
+            unknown_size: false,
         });
     }
-    r.seek(data.start + data.length)?;
+
+    cursor.enter()?;
+    let children = read_children(cursor, cursor.schema().level(header.id), damaged)?;
+    // Unknown-size masters end where the last child ends
+    let length = match header.size {
+        Some(size) => size,
+        None => cursor.position()? - data_start,
+    };
+    cursor.leave()?;
+
     Ok(ParsedElement {
-        id: id_vint.value,
-        header,
-        data,
-        children: None,
+        id: header.id,
+        header: header.header,
+        data: ByteRange {
+            start: data_start,
+            length,
+        },
+        children: Some(children),
+        unknown_size: header.size.is_none(),
     })
 }
 
-pub fn read_root<S: EbmlSchema, R: Read + Seek>(
-    r: &mut EbmlReader<R>,
-) -> Result<Vec<ParsedElement>, EbmlError> {
-    let mut elements = Vec::new();
-    while !r.at_eof()? {
-        elements.push(read_element::<S, R>(r)?);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebml::element::Element;
+    use std::io::Cursor;
+
+    struct TestSchema;
+
+    // Segment-like root 0x18538067 with Cluster-like children 0x1F43B675
+    impl EbmlSchema for TestSchema {
+        fn is_master(&self, id: u64) -> bool {
+            matches!(id, 0x1A45_DFA3 | 0x1853_8067 | 0x1F43_B675)
+        }
+
+        fn level(&self, id: u64) -> Option<u8> {
+            match id {
+                0x1A45_DFA3 | 0x1853_8067 => Some(0),
+                0x1F43_B675 => Some(1),
+                _ => None,
+            }
+        }
+    }
+
+    fn read_bytes(bytes: Vec<u8>) -> Result<Vec<ParsedElement>, EbmlError> {
+        let mut reader = EbmlReader::new(Cursor::new(bytes));
+        read_root(&mut reader, &TestSchema)
+    }
+
+    #[test]
+    fn test_read_unknown_size_until_eof() {
+        let bytes = vec![
+            0x18, 0x53, 0x80, 0x67, 0xFF, // Segment, unknown size
+            0xEC, 0x81, 0x00, // Void, 1 byte
+        ];
+        let root = read_bytes(bytes).unwrap();
+        assert_eq!(root.len(), 1);
+        assert!(root[0].unknown_size);
+        assert_eq!(root[0].data.start, 5);
+        assert_eq!(root[0].data.length, 3);
+        assert_eq!(root[0].children.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_read_unknown_size_ends_at_sibling() {
+        let bytes = vec![
+            0x18, 0x53, 0x80, 0x67, 0xFF, // Segment, unknown size
+            0x1F, 0x43, 0xB6, 0x75, 0xFF, // Cluster, unknown size
+            0xE7, 0x81, 0x00, // Timestamp
+            0x1F, 0x43, 0xB6, 0x75, 0x83, // Cluster, 3 bytes
+            0xE7, 0x81, 0x01, // Timestamp
+            0x18, 0x53, 0x80, 0x67, 0x80, // Segment, empty
+        ];
+        let root = read_bytes(bytes).unwrap();
+        assert_eq!(root.len(), 2);
+
+        let clusters = root[0].children.as_ref().unwrap();
+        assert_eq!(clusters.len(), 2);
+        assert!(clusters[0].unknown_size);
+        assert_eq!(clusters[0].data.length, 3);
+        assert_eq!(clusters[0].children.as_ref().unwrap().len(), 1);
+        assert!(!clusters[1].unknown_size);
+        assert_eq!(root[0].data.length, 16);
+        assert_eq!(root[1].header.start, 21);
+    }
+
+    #[test]
+    fn test_read_unknown_size_bounded_by_parent() {
+        let bytes = vec![
+            0x18, 0x53, 0x80, 0x67, 0x88, // Segment, 8 bytes
+            0x1F, 0x43, 0xB6, 0x75, 0xFF, // Cluster, unknown size
+            0xE7, 0x81, 0x00, // Timestamp
+            0xEC, 0x80, // Void, outside of Segment
+        ];
+        let root = read_bytes(bytes).unwrap();
+        assert_eq!(root.len(), 2);
+        let cluster = &root[0].children.as_ref().unwrap()[0];
+        assert_eq!(cluster.data.length, 3);
+    }
+
+    #[test]
+    fn test_read_unknown_size_non_master() {
+        let bytes = vec![0xEC, 0xFF];
+        let error = read_bytes(bytes).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            EbmlError::UnknownSizeNotAllowed(0xEC)
+        ));
+        assert_eq!(error.offset(), Some(0));
+    }
+
+    #[test]
+    fn test_read_lenient_invalid_vint() {
+        let bytes = vec![
+            0x18, 0x53, 0x80, 0x67, 0xFF, // Segment, unknown size
+            0x1F, 0x43, 0xB6, 0x75, 0x83, // Cluster, 3 bytes
+            0xE7, 0x81, 0x00, // Timestamp
+            0x1F, 0x43, 0xB6, 0x75, 0x84, // Cluster, 4 bytes
+            0x00, 0x00, 0x00, 0x00, // Invalid VINT
+            0x1F, 0x43, 0xB6, 0x75, 0x83, // Cluster, 3 bytes
+            0xE7, 0x81, 0x02, // Timestamp
+        ];
+        let error = read_bytes(bytes.clone()).unwrap_err();
+        assert!(matches!(error.kind(), EbmlError::InvalidVint));
+        assert_eq!(error.offset(), Some(18));
+        assert_eq!(
+            error.path().unwrap().ids().collect::<Vec<_>>(),
+            vec![0x1853_8067, 0x1F43_B675]
+        );
+        assert_eq!(
+            error.to_string(),
+            "invalid VINT encoding at offset 18 in 0x18538067/0x1F43B675"
+        );
+
+        let mut reader = EbmlReader::new(Cursor::new(bytes));
+        let (root, damaged) = read_root_lenient(&mut reader, &TestSchema).unwrap();
+        let clusters = root[0].children.as_ref().unwrap();
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[1].header.start, 22);
+        assert_eq!(damaged.len(), 1);
+        assert_eq!(damaged[0].range.start, 13);
+        assert_eq!(damaged[0].range.length, 9);
+        assert!(matches!(damaged[0].error.kind(), EbmlError::InvalidVint));
+    }
+
+    #[test]
+    fn test_read_lenient_overrun() {
+        let bytes = vec![
+            0x18, 0x53, 0x80, 0x67, 0x88, // Segment, 8 bytes
+            0x1F, 0x43, 0xB6, 0x75, 0x90, // Cluster, 16 bytes
+            0xE7, 0x81, 0x00, // Timestamp
+            0xEC, 0x80, // Void, outside of Segment
+        ];
+        assert!(matches!(
+            read_bytes(bytes.clone()).unwrap_err().kind(),
+            EbmlError::ElementOverrun { position: 5, .. }
+        ));
+
+        let mut reader = EbmlReader::new(Cursor::new(bytes));
+        let (root, damaged) = read_root_lenient(&mut reader, &TestSchema).unwrap();
+        assert_eq!(root.len(), 2);
+        assert!(root[0].children.as_ref().unwrap().is_empty());
+        assert_eq!(damaged[0].range.start, 5);
+        assert_eq!(damaged[0].range.length, 8);
+    }
+
+    #[test]
+    fn test_read_lenient_limits() {
+        let mut bytes = vec![0x18, 0x53, 0x80, 0x67, 0xFF]; // Segment, unknown size
+        for _ in 0..100 {
+            bytes.extend([0x1F, 0x43, 0xB6, 0x75, 0x83, 0xE7, 0x81, 0x00]); // Cluster
+        }
+        let mut reader = EbmlReader::with_limits(
+            Cursor::new(bytes),
+            ReaderLimits {
+                max_elements: 5,
+                ..ReaderLimits::default()
+            },
+        );
+        let error = read_root_lenient(&mut reader, &TestSchema).unwrap_err();
+        assert!(matches!(error.kind(), EbmlError::TooManyElements(5)));
+        assert_eq!(error.offset(), Some(21));
+    }
+
+    #[test]
+    fn test_reader_limits_unknown_size() {
+        let mut bytes = vec![0x18, 0x53, 0x80, 0x67, 0xFF]; // Segment, unknown size
+        for _ in 0..3 {
+            bytes.extend([0x1F, 0x43, 0xB6, 0x75, 0xFF, 0xE7, 0x81, 0x00]); // Cluster, unknown size
+        }
+        // Elements ending an unknown-size master are only counted once
+        let read_limited = |max_elements| {
+            let limits = ReaderLimits {
+                max_elements,
+                ..ReaderLimits::default()
+            };
+            let mut reader = EbmlReader::with_limits(Cursor::new(bytes.clone()), limits);
+            read_root(&mut reader, &TestSchema)
+        };
+        assert_eq!(
+            read_limited(7).unwrap()[0].children.as_ref().unwrap().len(),
+            3
+        );
+        let error = read_limited(6).unwrap_err();
+        assert!(matches!(error.kind(), EbmlError::TooManyElements(6)));
+        assert_eq!(error.offset(), Some(26));
+    }
+
+    #[test]
+    fn test_reader_limits() {
+        let limits = ReaderLimits {
+            max_payload_size: 16,
+            max_depth: 1,
+            max_children: 2,
+            max_elements: 4,
+            strict_ids: false,
+        };
+        let read_limited = |bytes: Vec<u8>| {
+            let mut reader = EbmlReader::with_limits(Cursor::new(bytes), limits);
+            read_root(&mut reader, &TestSchema)
+        };
+
+        let mut reader = EbmlReader::with_limits(Cursor::new(vec![0xEC, 0x80]), limits);
+        assert!(matches!(
+            reader.read_range(&ByteRange {
+                start: 0,
+                length: 1 << 40,
+            }),
+            Err(EbmlError::PayloadTooLarge { limit: 16, .. })
+        ));
+
+        let nested = vec![
+            0x18, 0x53, 0x80, 0x67, 0x85, // Segment, 5 bytes
+            0x1F, 0x43, 0xB6, 0x75, 0x80, // Cluster, empty
+        ];
+        let error = read_limited(nested).unwrap_err();
+        assert!(matches!(error.kind(), EbmlError::DepthLimitExceeded(1)));
+
+        let voids = [0xEC, 0x80].repeat(3);
+        let error = read_limited(voids).unwrap_err();
+        assert!(matches!(error.kind(), EbmlError::TooManyChildren(2)));
+
+        let mut reader = EbmlReader::with_limits(
+            Cursor::new([0xEC, 0x80].repeat(5)),
+            ReaderLimits {
+                max_children: 10,
+                ..limits
+            },
+        );
+        let error = read_root(&mut reader, &TestSchema).unwrap_err();
+        assert!(matches!(error.kind(), EbmlError::TooManyElements(4)));
+    }
+
+    #[test]
+    fn test_read_header_max_lengths() {
+        let error = read_bytes(vec![0x08, 0x12, 0x34, 0x56, 0x78, 0x80]).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            EbmlError::IdTooLong { length: 5, .. }
+        ));
+        // Non-shortest IDs are only rejected with `strict_ids`
+        assert_eq!(read_bytes(vec![0x40, 0x01, 0x80]).unwrap().len(), 1);
+        let mut reader = EbmlReader::with_limits(
+            Cursor::new(vec![0x40, 0x01, 0x80]),
+            ReaderLimits {
+                strict_ids: true,
+                ..ReaderLimits::default()
+            },
+        );
+        let error = read_root(&mut reader, &TestSchema).unwrap_err();
+        assert!(matches!(error.kind(), EbmlError::NonCanonicalId(0x4001)));
+
+        let header = Element::Master {
+            id: 0x1A45_DFA3,
+            children: vec![Element::Raw {
+                id: 0x42F3,
+                data: vec![0x04],
+            }],
+        };
+        let mut bytes = header.to_bytes().unwrap();
+        bytes.extend([0xEC, 0x10, 0x00, 0x00, 0x00]); // Void, 4-byte size
+        let mut reader = EbmlReader::new(Cursor::new(bytes.clone()));
+        assert_eq!(read_root(&mut reader, &TestSchema).unwrap().len(), 2);
+        assert_eq!(reader.max_lengths(), (4, 4));
+
+        bytes.extend([0xEC, 0x01, 0, 0, 0, 0, 0, 0, 0]); // Void, 8-byte size
+        let error = read_bytes(bytes).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            EbmlError::SizeTooLong { length: 8, max: 4 }
+        ));
+
+        for data in [vec![0x00], vec![0x09], vec![0x01; 9]] {
+            let header = Element::Master {
+                id: 0x1A45_DFA3,
+                children: vec![Element::Raw { id: 0x42F3, data }],
+            };
+            let error = read_bytes(header.to_bytes().unwrap()).unwrap_err();
+            assert!(matches!(error.kind(), EbmlError::InvalidMaxLength(0x42F3)));
+            assert_eq!(error.offset(), Some(0));
+        }
     }
-    Ok(elements)
 }
@@ -0,0 +1,556 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::path::Path;
+use std::{fs, io};
+
+use thiserror::Error;
+
+use crate::ebml::primitives::{Value, parse_float_literal};
+use crate::ebml::reader::EbmlSchema;
+
+#[derive(Error, Debug)]
+pub enum SchemaError {
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("XML error: {0}")]
+    Xml(#[from] roxmltree::Error),
+
+    #[error("root element is not EBMLSchema")]
+    InvalidRoot,
+
+    #[error("element {element:?} is missing attribute {attribute}")]
+    MissingAttribute {
+        element: String,
+        attribute: &'static str,
+    },
+
+    #[error("element {element:?} has invalid {attribute}: {value:?}")]
+    InvalidAttribute {
+        element: String,
+        attribute: &'static str,
+        value: String,
+    },
+
+    #[error("duplicate element ID {0:#X}")]
+    DuplicateId(u64),
+}
+
+// EBML element types as defined in RFC 8794
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElementType {
+    Integer,
+    UnsignedInteger,
+    Float,
+    String,
+    Utf8,
+    Date,
+    Master,
+    Binary,
+}
+
+impl ElementType {
+    // Parses the `type` attribute of an EBML schema element
+    pub fn from_schema_name(name: &str) -> Option<Self> {
+        match name {
+            "integer" => Some(Self::Integer),
+            "uinteger" => Some(Self::UnsignedInteger),
+            "float" => Some(Self::Float),
+            "string" => Some(Self::String),
+            "utf-8" => Some(Self::Utf8),
+            "date" => Some(Self::Date),
+            "master" => Some(Self::Master),
+            "binary" => Some(Self::Binary),
+            _ => None,
+        }
+    }
+}
+
+// Definition of a single element in an EBML schema
+// Borrowed strings are used for built-in and generated definitions,
+// owned strings for definitions loaded at runtime
+#[derive(Debug, Clone)]
+pub struct ElementDef {
+    pub id: u64,
+    pub name: Cow<'static, str>,
+    // EBML path, e.g. `\Segment\Info\TimestampScale` or `\(-\)Void` for global elements
+    pub path: Cow<'static, str>,
+    pub element_type: ElementType,
+    // Default value in the textual form used by the schema
+    pub default: Option<Cow<'static, str>>,
+    // Allowed values in the textual form used by the schema, e.g. `not 0` or `1-8`
+    pub range: Option<Cow<'static, str>>,
+    pub min_occurs: u32,
+    // `None` if the element may occur any number of times
+    pub max_occurs: Option<u32>,
+    pub recurring: bool,
+    pub unknown_size_allowed: bool,
+}
+
+impl ElementDef {
+    // Global elements (e.g. Void and CRC-32) may occur at any level below their path prefix
+    pub fn is_global(&self) -> bool {
+        self.path.contains('(')
+    }
+
+    // Returns the level of the element (0 for root elements), `None` for global elements
+    // Recursive elements (marked with `+`) report the level of their first occurrence
+    pub fn level(&self) -> Option<u8> {
+        if self.is_global() {
+            return None;
+        }
+        let depth = self.path.split('\\').filter(|s| !s.is_empty()).count();
+        u8::try_from(depth.checked_sub(1)?).ok()
+    }
+
+    // Returns the path of the parent element, `None` for root and global elements
+    pub fn parent_path(&self) -> Option<&str> {
+        if self.is_global() {
+            return None;
+        }
+        let (parent, _) = self.path.rsplit_once('\\')?;
+        (!parent.is_empty()).then_some(parent)
+    }
+
+    // Recursive elements (marked with `+`) may also be children of themselves
+    pub fn is_recursive(&self) -> bool {
+        self.path
+            .rsplit_once('\\')
+            .is_some_and(|(_, name)| name.starts_with('+'))
+    }
+
+    // Returns whether the element may be a child of `parent` (`None` at the root level),
+    // where `depth` is the number of ancestors of the element
+    // Global elements are only checked against the number of ancestors their path allows
+    pub fn allows_parent(&self, parent: Option<&ElementDef>, depth: usize) -> bool {
+        if let Some((prefix, occurrence)) = self.path.split_once("\\(") {
+            let prefix_depth = prefix.split('\\').filter(|s| !s.is_empty()).count();
+            let occurrence = occurrence
+                .split_once("\\)")
+                .map_or("", |(occurrence, _)| occurrence);
+            let (min, max) = occurrence
+                .split_once('-')
+                .unwrap_or((occurrence, occurrence));
+            let Some(depth) = depth.checked_sub(prefix_depth) else {
+                return false;
+            };
+            return min.parse().map_or(true, |min: usize| depth >= min)
+                && max.parse().map_or(true, |max: usize| depth <= max);
+        }
+        match (self.parent_path(), parent) {
+            (None, None) => true,
+            (Some(parent_path), Some(parent)) => {
+                parent.path == parent_path || (parent.id == self.id && self.is_recursive())
+            }
+            _ => false,
+        }
+    }
+
+    // Returns whether `value` satisfies the range of the element
+    // Parts of a range separated by commas must all be satisfied, e.g. `>= -0x5Ap+0, <= 0x5Ap+0`
+    // Ranges which cannot be evaluated, e.g. for strings, are treated as satisfied
+    pub fn in_range(&self, value: &Value) -> bool {
+        self.range.as_deref().is_none_or(|range| {
+            range
+                .split(',')
+                .all(|part| range_matches(part.trim(), value))
+        })
+    }
+}
+
+fn range_matches(range: &str, value: &Value) -> bool {
+    let check = |bound: &str, accept: fn(Ordering) -> bool| {
+        compare_bound(value, bound.trim()).is_none_or(accept)
+    };
+    if let Some(bound) = range.strip_prefix("not ") {
+        check(bound, Ordering::is_ne)
+    } else if let Some(bound) = range.strip_prefix(">=") {
+        check(bound, Ordering::is_ge)
+    } else if let Some(bound) = range.strip_prefix('>') {
+        check(bound, Ordering::is_gt)
+    } else if let Some(bound) = range.strip_prefix("<=") {
+        check(bound, Ordering::is_le)
+    } else if let Some(bound) = range.strip_prefix('<') {
+        check(bound, Ordering::is_lt)
+    } else if let Some((lower, upper)) = split_interval(range) {
+        check(lower, Ordering::is_ge) && check(upper, Ordering::is_le)
+    } else {
+        check(range, Ordering::is_eq)
+    }
+}
+
+// Splits an interval such as `1-8` or `0x0p+0-0x1p+0` at the `-` between its bounds,
+// skipping signs of the lower bound and of exponents
+fn split_interval(range: &str) -> Option<(&str, &str)> {
+    let hex = range.trim_start_matches('-').starts_with("0x");
+    let bytes = range.as_bytes();
+    let index = (1..bytes.len()).find(|&i| {
+        let previous = bytes[i - 1];
+        bytes[i] == b'-'
+            && if hex {
+                previous.is_ascii_hexdigit()
+            } else {
+                previous.is_ascii_digit()
+            }
+    })?;
+    Some((&range[..index], &range[index + 1..]))
+}
+
+// Compares `value` with a bound written in the textual form used by schemas
+fn compare_bound(value: &Value, bound: &str) -> Option<Ordering> {
+    match value {
+        Value::Integer(value) => Some(i128::from(*value).cmp(&bound.parse().ok()?)),
+        Value::UnsignedInteger(value) => Some(i128::from(*value).cmp(&bound.parse().ok()?)),
+        Value::Date(date) => Some(i128::from(date.nanoseconds).cmp(&bound.parse().ok()?)),
+        Value::Float(value) => value.partial_cmp(&parse_float_literal(bound)?),
+        Value::String(_) | Value::Binary(_) => None,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+const fn header_element(
+    id: u64,
+    name: &'static str,
+    path: &'static str,
+    element_type: ElementType,
+    default: Option<&'static str>,
+    range: Option<&'static str>,
+    min_occurs: u32,
+    max_occurs: Option<u32>,
+) -> ElementDef {
+    ElementDef {
+        id,
+        name: Cow::Borrowed(name),
+        path: Cow::Borrowed(path),
+        element_type,
+        default: match default {
+            Some(default) => Some(Cow::Borrowed(default)),
+            None => None,
+        },
+        range: match range {
+            Some(range) => Some(Cow::Borrowed(range)),
+            None => None,
+        },
+        min_occurs,
+        max_occurs,
+        recurring: false,
+        unknown_size_allowed: false,
+    }
+}
+
+// EBML header and global elements defined by RFC 8794 itself,
+// which document type schemas such as `ebml_matroska.xml` do not repeat
+#[rustfmt::skip]
+pub const EBML_ELEMENTS: &[ElementDef] = &[
+    header_element(0x1A45_DFA3, "EBML", "\\EBML", ElementType::Master, None, None, 1, Some(1)),
+    header_element(0x4286, "EBMLVersion", "\\EBML\\EBMLVersion", ElementType::UnsignedInteger, Some("1"), Some("not 0"), 1, Some(1)),
+    header_element(0x42F7, "EBMLReadVersion", "\\EBML\\EBMLReadVersion", ElementType::UnsignedInteger, Some("1"), Some("1"), 1, Some(1)),
+    header_element(0x42F2, "EBMLMaxIDLength", "\\EBML\\EBMLMaxIDLength", ElementType::UnsignedInteger, Some("4"), Some(">=4"), 1, Some(1)),
+    header_element(0x42F3, "EBMLMaxSizeLength", "\\EBML\\EBMLMaxSizeLength", ElementType::UnsignedInteger, Some("8"), Some("1-8"), 1, Some(1)),
+    header_element(0x4282, "DocType", "\\EBML\\DocType", ElementType::String, None, None, 1, Some(1)),
+    header_element(0x4287, "DocTypeVersion", "\\EBML\\DocTypeVersion", ElementType::UnsignedInteger, Some("1"), Some("not 0"), 1, Some(1)),
+    header_element(0x4285, "DocTypeReadVersion", "\\EBML\\DocTypeReadVersion", ElementType::UnsignedInteger, Some("1"), Some("not 0"), 1, Some(1)),
+    header_element(0x4281, "DocTypeExtension", "\\EBML\\DocTypeExtension", ElementType::Master, None, None, 0, None),
+    header_element(0x4283, "DocTypeExtensionName", "\\EBML\\DocTypeExtension\\DocTypeExtensionName", ElementType::String, None, None, 1, Some(1)),
+    header_element(0x4284, "DocTypeExtensionVersion", "\\EBML\\DocTypeExtension\\DocTypeExtensionVersion", ElementType::UnsignedInteger, None, Some("not 0"), 1, Some(1)),
+    header_element(0xBF, "CRC-32", "\\(1-\\)CRC-32", ElementType::Binary, None, None, 0, Some(1)),
+    header_element(0xEC, "Void", "\\(-\\)Void", ElementType::Binary, None, None, 0, None),
+];
+
+// EBML schema loaded at runtime from an RFC 8794 EBMLSchema XML file, e.g. `ebml_matroska.xml`
+// The EBML header and global elements from `EBML_ELEMENTS` are always included
+#[derive(Debug, Clone)]
+pub struct XmlSchema {
+    doc_type: String,
+    version: u64,
+    // Elements in document order, followed by any missing built-in elements
+    elements: Vec<ElementDef>,
+    ids: HashMap<u64, usize>,
+    names: HashMap<String, usize>,
+}
+
+fn attribute<'a>(
+    node: roxmltree::Node<'a, '_>,
+    name: &str,
+    attribute: &'static str,
+) -> Result<&'a str, SchemaError> {
+    node.attribute(attribute)
+        .ok_or_else(|| SchemaError::MissingAttribute {
+            element: name.to_string(),
+            attribute,
+        })
+}
+
+// Parses a decimal or `0x`-prefixed hexadecimal attribute value
+fn parse_number<T: TryFrom<u64>>(
+    name: &str,
+    attribute: &'static str,
+    value: &str,
+) -> Result<T, SchemaError> {
+    let parsed = match value.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => value.parse(),
+    };
+    parsed
+        .ok()
+        .and_then(|number| T::try_from(number).ok())
+        .ok_or_else(|| SchemaError::InvalidAttribute {
+            element: name.to_string(),
+            attribute,
+            value: value.to_string(),
+        })
+}
+
+fn parse_element(node: roxmltree::Node) -> Result<ElementDef, SchemaError> {
+    let name = node.attribute("name").unwrap_or_default();
+    let path = attribute(node, name, "path")?;
+    let id = parse_number(name, "id", attribute(node, name, "id")?)?;
+
+    let type_name = attribute(node, name, "type")?;
+    let element_type =
+        ElementType::from_schema_name(type_name).ok_or_else(|| SchemaError::InvalidAttribute {
+            element: name.to_string(),
+            attribute: "type",
+            value: type_name.to_string(),
+        })?;
+
+    let min_occurs = match node.attribute("minOccurs") {
+        Some(value) => parse_number(name, "minOccurs", value)?,
+        None => 0,
+    };
+    let max_occurs = match node.attribute("maxOccurs") {
+        Some("unbounded") | None => None,
+        Some(value) => Some(parse_number(name, "maxOccurs", value)?),
+    };
+
+    Ok(ElementDef {
+        id,
+        name: Cow::Owned(name.to_string()),
+        path: Cow::Owned(path.to_string()),
+        element_type,
+        default: node.attribute("default").map(|v| Cow::Owned(v.to_string())),
+        range: node.attribute("range").map(|v| Cow::Owned(v.to_string())),
+        min_occurs,
+        max_occurs,
+        recurring: node.attribute("recurring") == Some("1"),
+        unknown_size_allowed: node.attribute("unknownsizeallowed") == Some("1"),
+    })
+}
+
+impl XmlSchema {
+    pub fn from_xml(xml: &str) -> Result<Self, SchemaError> {
+        let document = roxmltree::Document::parse(xml)?;
+        let root = document.root_element();
+        if root.tag_name().name() != "EBMLSchema" {
+            return Err(SchemaError::InvalidRoot);
+        }
+
+        let doc_type = attribute(root, "EBMLSchema", "docType")?.to_string();
+        let version = parse_number(
+            "EBMLSchema",
+            "version",
+            attribute(root, "EBMLSchema", "version")?,
+        )?;
+
+        let mut schema = Self {
+            doc_type,
+            version,
+            elements: Vec::new(),
+            ids: HashMap::new(),
+            names: HashMap::new(),
+        };
+
+        for node in root
+            .children()
+            .filter(|node| node.is_element() && node.tag_name().name() == "element")
+        {
+            let element = parse_element(node)?;
+            if schema.ids.contains_key(&element.id) {
+                return Err(SchemaError::DuplicateId(element.id));
+            }
+            schema.insert(element);
+        }
+
+        // The schema may override the built-in definitions, but cannot omit them
+        for element in EBML_ELEMENTS {
+            if !schema.ids.contains_key(&element.id) {
+                schema.insert(element.clone());
+            }
+        }
+
+        Ok(schema)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, SchemaError> {
+        Self::from_xml(&fs::read_to_string(path)?)
+    }
+
+    fn insert(&mut self, element: ElementDef) {
+        let index = self.elements.len();
+        self.ids.insert(element.id, index);
+        self.names.insert(element.name.to_string(), index);
+        self.elements.push(element);
+    }
+
+    pub fn doc_type(&self) -> &str {
+        &self.doc_type
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub fn element(&self, id: u64) -> Option<&ElementDef> {
+        self.ids.get(&id).map(|&index| &self.elements[index])
+    }
+
+    pub fn element_by_name(&self, name: &str) -> Option<&ElementDef> {
+        self.names.get(name).map(|&index| &self.elements[index])
+    }
+
+    // Returns all elements in document order
+    pub fn elements(&self) -> impl Iterator<Item = &ElementDef> {
+        self.elements.iter()
+    }
+}
+
+impl EbmlSchema for XmlSchema {
+    fn is_master(&self, id: u64) -> bool {
+        self.element(id)
+            .is_some_and(|element| element.element_type == ElementType::Master)
+    }
+
+    fn definition(&self, id: u64) -> Option<&ElementDef> {
+        self.element(id)
+    }
+
+    fn definitions(&self) -> Box<dyn Iterator<Item = &ElementDef> + '_> {
+        Box::new(self.elements())
+    }
+
+    fn id(&self, name: &str) -> Option<u64> {
+        self.element_by_name(name).map(|element| element.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_SCHEMA: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<EBMLSchema xmlns="urn:ietf:rfc:8794" docType="matroska" version="4">
+  <element name="Segment" path="\Segment" id="0x18538067" type="master" minOccurs="1" maxOccurs="1" unknownsizeallowed="1">
+    <documentation lang="en" purpose="definition">The Root Element that contains all other Top-Level Elements.</documentation>
+  </element>
+  <element name="Info" path="\Segment\Info" id="0x1549A966" type="master" minOccurs="1" maxOccurs="1" recurring="1"/>
+  <element name="TimestampScale" path="\Segment\Info\TimestampScale" id="0x2AD7B1" type="uinteger" range="not 0" default="1000000" minOccurs="1" maxOccurs="1"/>
+  <element name="ChapterAtom" path="\Segment\Chapters\EditionEntry\+ChapterAtom" id="0xB6" type="master" minOccurs="1"/>
+</EBMLSchema>
+"#;
+
+    #[test]
+    fn test_parse_schema() {
+        let schema = XmlSchema::from_xml(SAMPLE_SCHEMA).unwrap();
+        assert_eq!(schema.doc_type(), "matroska");
+        assert_eq!(schema.version(), 4);
+
+        let timestamp_scale = schema.element_by_name("TimestampScale").unwrap();
+        assert_eq!(timestamp_scale.id, 0x002A_D7B1);
+        assert_eq!(timestamp_scale.element_type, ElementType::UnsignedInteger);
+        assert_eq!(timestamp_scale.default.as_deref(), Some("1000000"));
+        assert_eq!(timestamp_scale.range.as_deref(), Some("not 0"));
+        assert_eq!(timestamp_scale.min_occurs, 1);
+        assert_eq!(timestamp_scale.max_occurs, Some(1));
+        assert_eq!(timestamp_scale.parent_path(), Some("\\Segment\\Info"));
+
+        let segment = schema.element(0x1853_8067).unwrap();
+        assert!(segment.unknown_size_allowed);
+        assert_eq!(segment.parent_path(), None);
+        assert!(schema.element(0x1549_A966).unwrap().recurring);
+        assert_eq!(schema.element(0xB6).unwrap().max_occurs, None);
+    }
+
+    #[test]
+    fn test_schema_levels() {
+        let schema = XmlSchema::from_xml(SAMPLE_SCHEMA).unwrap();
+        assert!(schema.is_master(0x1853_8067));
+        assert!(!schema.is_master(0x002A_D7B1));
+        assert!(schema.is_master(0x1A45_DFA3));
+        assert_eq!(schema.level(0x1853_8067), Some(0));
+        assert_eq!(schema.level(0x002A_D7B1), Some(2));
+        assert_eq!(schema.level(0xB6), Some(3));
+        assert_eq!(schema.level(0xEC), None);
+        assert_eq!(schema.level(0x1234), None);
+    }
+
+    #[test]
+    fn test_schema_parents() {
+        let schema = XmlSchema::from_xml(SAMPLE_SCHEMA).unwrap();
+        let segment = schema.definition(0x1853_8067);
+        let info = schema.definition(0x1549_A966);
+        let chapter_atom = schema.element(0xB6).unwrap();
+        let timestamp_scale = schema.element(0x002A_D7B1).unwrap();
+
+        assert!(segment.unwrap().allows_parent(None, 0));
+        assert!(!segment.unwrap().allows_parent(info, 1));
+        assert!(timestamp_scale.allows_parent(info, 2));
+        assert!(!timestamp_scale.allows_parent(segment, 1));
+        assert!(chapter_atom.allows_parent(Some(chapter_atom), 4));
+
+        let crc = schema.element(0xBF).unwrap();
+        let void = schema.element(0xEC).unwrap();
+        assert!(crc.allows_parent(info, 2));
+        assert!(!crc.allows_parent(None, 0));
+        assert!(void.allows_parent(None, 0));
+    }
+
+    #[test]
+    fn test_schema_ranges() {
+        let mut element = EBML_ELEMENTS[0].clone();
+        let mut in_range = |range: &str, value: Value| {
+            element.range = Some(Cow::Owned(range.to_string()));
+            element.in_range(&value)
+        };
+
+        assert!(in_range("not 0", Value::UnsignedInteger(1)));
+        assert!(!in_range("not 0", Value::UnsignedInteger(0)));
+        assert!(in_range("1-8", Value::UnsignedInteger(8)));
+        assert!(!in_range("1-8", Value::UnsignedInteger(9)));
+        assert!(!in_range(">=4", Value::UnsignedInteger(3)));
+        assert!(in_range("1", Value::UnsignedInteger(1)));
+        assert!(!in_range("> 0x0p+0", Value::Float(0.0)));
+        assert!(in_range("0x0p+0-0x1p+0", Value::Float(0.5)));
+        assert!(!in_range("0x0p+0-0x1p+0", Value::Float(1.5)));
+        assert!(in_range(">= -0x5Ap+0, <= 0x5Ap+0", Value::Float(-90.0)));
+        assert!(!in_range(">= -0x5Ap+0, <= 0x5Ap+0", Value::Float(90.5)));
+        assert!(in_range("-8--1", Value::Integer(-1)));
+        assert!(in_range("not 0", Value::String("0".to_string())));
+    }
+
+    #[test]
+    fn test_invalid_schema() {
+        let missing_id = r#"<EBMLSchema docType="x" version="1"><element name="A" path="\A" type="master"/></EBMLSchema>"#;
+        assert!(matches!(
+            XmlSchema::from_xml(missing_id),
+            Err(SchemaError::MissingAttribute {
+                attribute: "id",
+                ..
+            })
+        ));
+
+        let invalid_type = r#"<EBMLSchema docType="x" version="1"><element name="A" path="\A" id="0x81" type="list"/></EBMLSchema>"#;
+        assert!(matches!(
+            XmlSchema::from_xml(invalid_type),
+            Err(SchemaError::InvalidAttribute {
+                attribute: "type",
+                ..
+            })
+        ));
+
+        assert!(matches!(
+            XmlSchema::from_xml("<Schema/>"),
+            Err(SchemaError::InvalidRoot)
+        ));
+    }
+}
@@ -0,0 +1,329 @@
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+
+use memmap2::Mmap;
+
+// Random-access byte input for `EbmlReader`
+// Implemented for every `Read + Seek`, and by `BufferedSource` and `MmapSource`
+// which avoid a syscall for every small read, peek and seek
+pub trait ByteSource: Read {
+    fn position(&mut self) -> io::Result<u64>;
+
+    fn seek_to(&mut self, position: u64) -> io::Result<()>;
+
+    // Returns whether there are no bytes left at the current position
+    fn at_eof(&mut self) -> io::Result<bool>;
+
+    // Returns `length` bytes starting at `start`, borrowed from the source when possible
+    // The position afterwards is `start + length`
+    fn read_range(&mut self, start: u64, length: usize) -> io::Result<Cow<'_, [u8]>>;
+}
+
+impl<T: Read + Seek> ByteSource for T {
+    fn position(&mut self) -> io::Result<u64> {
+        self.stream_position()
+    }
+
+    fn seek_to(&mut self, position: u64) -> io::Result<()> {
+        self.seek(SeekFrom::Start(position)).map(|_| ())
+    }
+
+    fn at_eof(&mut self) -> io::Result<bool> {
+        let position = self.stream_position()?;
+        let mut buf = [0u8; 1];
+        if self.read(&mut buf)? == 0 {
+            return Ok(true);
+        }
+        self.seek_to(position)?;
+        Ok(false)
+    }
+
+    fn read_range(&mut self, start: u64, length: usize) -> io::Result<Cow<'_, [u8]>> {
+        let mut buf = vec![0u8; length];
+        self.seek_to(start)?;
+        self.read_exact(&mut buf)?;
+        Ok(Cow::Owned(buf))
+    }
+}
+
+const DEFAULT_BUFFER_CAPACITY: usize = 64 * 1024;
+
+// Buffered source over any `Read + Seek`
+// Unlike `std::io::BufReader`, seeking within the buffered window keeps the buffer
+pub struct BufferedSource<R: Read + Seek> {
+    inner: R,
+    buffer: Vec<u8>,
+    capacity: usize,
+    // Absolute position of `buffer[0]`
+    buffer_start: u64,
+    // Absolute position of `inner`
+    inner_position: u64,
+    position: u64,
+}
+
+impl<R: Read + Seek> BufferedSource<R> {
+    pub fn new(inner: R) -> Self {
+        Self::with_capacity(DEFAULT_BUFFER_CAPACITY, inner)
+    }
+
+    pub fn with_capacity(capacity: usize, mut inner: R) -> Self {
+        // Fall back to 0 for sources that cannot report a position, the first fill seeks anyway
+        let inner_position = inner.stream_position().unwrap_or(0);
+        Self {
+            inner,
+            buffer: Vec::with_capacity(capacity),
+            capacity: capacity.max(1),
+            buffer_start: inner_position,
+            inner_position,
+            position: inner_position,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    // Returns the buffered bytes from the current position onwards
+    fn buffered(&self) -> &[u8] {
+        let buffer_end = self.buffer_start + self.buffer.len() as u64;
+        if self.position < self.buffer_start || self.position >= buffer_end {
+            return &[];
+        }
+        // Safe: offset is smaller than buffer.len()
+        let offset = usize::try_from(self.position - self.buffer_start).unwrap_or(0);
+        &self.buffer[offset..]
+    }
+
+    // Refills the buffer starting at the current position
+    fn fill(&mut self) -> io::Result<()> {
+        if self.inner_position != self.position {
+            self.inner.seek(SeekFrom::Start(self.position))?;
+            self.inner_position = self.position;
+        }
+        self.buffer.resize(self.capacity, 0);
+        let mut filled = 0;
+        while filled < self.capacity {
+            match self.inner.read(&mut self.buffer[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => {
+                    self.buffer.clear();
+                    return Err(e);
+                }
+            }
+        }
+        self.buffer.truncate(filled);
+        self.buffer_start = self.position;
+        self.inner_position = self.position + filled as u64;
+        Ok(())
+    }
+}
+
+impl<R: Read + Seek> Read for BufferedSource<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.buffered().is_empty() {
+            if buf.len() >= self.capacity {
+                // Large reads bypass the buffer
+                if self.inner_position != self.position {
+                    self.inner.seek(SeekFrom::Start(self.position))?;
+                }
+                let n = self.inner.read(buf)?;
+                self.position += n as u64;
+                self.inner_position = self.position;
+                return Ok(n);
+            }
+            self.fill()?;
+        }
+        let available = self.buffered();
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+impl<R: Read + Seek> ByteSource for BufferedSource<R> {
+    fn position(&mut self) -> io::Result<u64> {
+        Ok(self.position)
+    }
+
+    fn seek_to(&mut self, position: u64) -> io::Result<()> {
+        // Seeking is deferred until the buffer needs to be refilled
+        self.position = position;
+        Ok(())
+    }
+
+    fn at_eof(&mut self) -> io::Result<bool> {
+        if self.buffered().is_empty() {
+            self.fill()?;
+        }
+        Ok(self.buffered().is_empty())
+    }
+
+    fn read_range(&mut self, start: u64, length: usize) -> io::Result<Cow<'_, [u8]>> {
+        self.position = start;
+        if length <= self.capacity && self.buffered().len() < length {
+            self.fill()?;
+        }
+        if self.buffered().len() >= length {
+            self.position = start + length as u64;
+            let offset = usize::try_from(start - self.buffer_start).unwrap_or(0);
+            return Ok(Cow::Borrowed(&self.buffer[offset..offset + length]));
+        }
+        let mut buf = vec![0u8; length];
+        self.read_exact(&mut buf)?;
+        Ok(Cow::Owned(buf))
+    }
+}
+
+// Memory-mapped file source
+// Reads are plain memory copies, and `read_range` borrows directly from the mapping
+pub struct MmapSource {
+    mmap: Mmap,
+    position: u64,
+}
+
+impl MmapSource {
+    pub fn open(file: &File) -> io::Result<Self> {
+        // Safety: the mapping is only valid as long as the file is not modified externally,
+        // which is the same assumption every mmap-based reader has to make
+        let mmap = unsafe { Mmap::map(file)? };
+        Ok(Self { mmap, position: 0 })
+    }
+
+    pub fn len(&self) -> u64 {
+        self.mmap.len() as u64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mmap.is_empty()
+    }
+
+    // Returns the mapped bytes from `position` onwards, empty past the end
+    fn remaining(&self) -> &[u8] {
+        let start = usize::try_from(self.position)
+            .map_or(self.mmap.len(), |position| position.min(self.mmap.len()));
+        &self.mmap[start..]
+    }
+}
+
+impl Read for MmapSource {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.remaining().read(buf)?;
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+impl ByteSource for MmapSource {
+    fn position(&mut self) -> io::Result<u64> {
+        Ok(self.position)
+    }
+
+    fn seek_to(&mut self, position: u64) -> io::Result<()> {
+        self.position = position;
+        Ok(())
+    }
+
+    fn at_eof(&mut self) -> io::Result<bool> {
+        Ok(self.remaining().is_empty())
+    }
+
+    fn read_range(&mut self, start: u64, length: usize) -> io::Result<Cow<'_, [u8]>> {
+        self.position = start;
+        if self.remaining().len() < length {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
+        self.position = start + length as u64;
+        let start = usize::try_from(start).map_err(|_| io::ErrorKind::UnexpectedEof)?;
+        Ok(Cow::Borrowed(&self.mmap[start..start + length]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Write};
+
+    // Counts the reads hitting the inner reader
+    struct CountingCursor {
+        inner: Cursor<Vec<u8>>,
+        reads: usize,
+    }
+
+    impl Read for CountingCursor {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.reads += 1;
+            self.inner.read(buf)
+        }
+    }
+
+    impl Seek for CountingCursor {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.inner.seek(pos)
+        }
+    }
+
+    fn sample_data() -> Vec<u8> {
+        (0..=255).collect()
+    }
+
+    #[test]
+    fn test_buffered_source() {
+        let inner = CountingCursor {
+            inner: Cursor::new(sample_data()),
+            reads: 0,
+        };
+        let mut source = BufferedSource::with_capacity(64, inner);
+
+        let mut buf = [0u8; 4];
+        source.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [0, 1, 2, 3]);
+        assert!(!source.at_eof().unwrap());
+        source.seek_to(10).unwrap();
+        source.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [10, 11, 12, 13]);
+        assert!(matches!(
+            source.read_range(60, 4).unwrap(),
+            Cow::Borrowed(&[60, 61, 62, 63])
+        ));
+        assert_eq!(source.position().unwrap(), 64);
+        assert_eq!(source.inner.reads, 1);
+
+        assert_eq!(source.read_range(100, 100).unwrap().len(), 100);
+        source.seek_to(256).unwrap();
+        assert!(source.at_eof().unwrap());
+        source.seek_to(2).unwrap();
+        source.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_mmap_source() {
+        let path = std::env::temp_dir().join(format!("mkvedit-mmap-{}", std::process::id()));
+        File::create(&path)
+            .unwrap()
+            .write_all(&sample_data())
+            .unwrap();
+
+        let file = File::open(&path).unwrap();
+        let mut source = MmapSource::open(&file).unwrap();
+        assert_eq!(source.len(), 256);
+
+        let mut buf = [0u8; 2];
+        source.seek_to(254).unwrap();
+        source.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [254, 255]);
+        assert!(source.at_eof().unwrap());
+        assert!(matches!(
+            source.read_range(1, 3).unwrap(),
+            Cow::Borrowed(&[1, 2, 3])
+        ));
+        assert!(source.read_range(255, 2).is_err());
+
+        drop(source);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,542 @@
+use std::io::{self, Read};
+
+use crate::ebml::cursor::{check_header, ends_unknown_size};
+use crate::ebml::element::{EbmlId, EbmlSize};
+use crate::ebml::error::{self, EbmlError, ElementPath};
+use crate::ebml::reader::{
+    ByteRange, DEFAULT_MAX_ID_LENGTH, DEFAULT_MAX_SIZE_LENGTH, EBML_HEADER_ID, EbmlSchema,
+    ElementHeader, ParsedElement, ReaderLimits, apply_max_length, is_max_length_id, validate_id,
+};
+
+// Wrapper keeping track of the number of bytes read from `inner`
+struct CountingReader<R: Read> {
+    inner: R,
+    position: u64,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+// Forward-only EBML reader for non-seekable inputs such as pipes and sockets
+// Element data is either read or discarded in order,
+// and data that has already been passed cannot be read again
+pub struct EbmlStreamReader<R: Read> {
+    reader: CountingReader<R>,
+    peeked: Option<ElementHeader>,
+    // IDs of the masters being read, from the root down
+    path: Vec<u64>,
+    limits: ReaderLimits,
+    elements_read: usize,
+    // Maximum ID and size lengths, as declared by the EBML header
+    max_id_length: u8,
+    max_size_length: u8,
+    // Maximum lengths read from the EBML header being read, applied once it has been read
+    declared_max_lengths: Option<(u8, u8)>,
+}
+
+impl<R: Read> EbmlStreamReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_limits(reader, ReaderLimits::default())
+    }
+
+    pub fn with_limits(reader: R, limits: ReaderLimits) -> Self {
+        Self {
+            reader: CountingReader {
+                inner: reader,
+                position: 0,
+            },
+            peeked: None,
+            path: Vec::new(),
+            limits,
+            elements_read: 0,
+            max_id_length: DEFAULT_MAX_ID_LENGTH,
+            max_size_length: DEFAULT_MAX_SIZE_LENGTH,
+            declared_max_lengths: None,
+        }
+    }
+
+    pub fn limits(&self) -> &ReaderLimits {
+        &self.limits
+    }
+
+    // Sets the maximum ID and size lengths elements are validated against
+    // `read_element` and `read_root` do this themselves when they read an EBML header
+    pub fn set_max_lengths(&mut self, max_id_length: u8, max_size_length: u8) {
+        self.max_id_length = max_id_length;
+        self.max_size_length = max_size_length;
+    }
+
+    // Returns the current position in the stream
+    // If a header has been peeked, this is the start of that header
+    pub fn position(&self) -> u64 {
+        match &self.peeked {
+            Some(header) => header.header.start,
+            None => self.reader.position,
+        }
+    }
+
+    // Reads the first byte of the next element, `None` at EOF
+    fn read_first_byte(&mut self) -> Result<Option<u8>, EbmlError> {
+        let mut buf = [0u8; 1];
+        loop {
+            match self.reader.read(&mut buf) {
+                Ok(0) => return Ok(None),
+                Ok(_) => return Ok(Some(buf[0])),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(EbmlError::from(e)),
+            }
+        }
+    }
+
+    fn read_header(&mut self) -> Result<Option<ElementHeader>, EbmlError> {
+        let header_start = self.reader.position;
+        let Some(first) = self.read_first_byte()? else {
+            return Ok(None);
+        };
+        if self.elements_read >= self.limits.max_elements {
+            return Err(EbmlError::TooManyElements(self.limits.max_elements));
+        }
+        self.elements_read += 1;
+        let id = EbmlId::read_from(&mut [first].chain(&mut self.reader))?;
+        validate_id(&id, self.max_id_length, &self.limits)?;
+        let size = EbmlSize::read_from(&mut self.reader)?;
+        if size.length > self.max_size_length {
+            return Err(EbmlError::SizeTooLong {
+                length: size.length,
+                max: self.max_size_length,
+            });
+        }
+        Ok(Some(ElementHeader {
+            id: id.value,
+            header: ByteRange {
+                start: header_start,
+                length: u64::from(id.length + size.length),
+            },
+            size: size.value,
+        }))
+    }
+
+    // Takes the peeked header or reads the next one, `None` at EOF
+    fn take_header(&mut self) -> Result<Option<ElementHeader>, EbmlError> {
+        match self.peeked.take() {
+            Some(header) => Ok(Some(header)),
+            None => self.read_header(),
+        }
+    }
+
+    // Reads the next header into `peeked` unless already there, `None` at EOF
+    fn peek(&mut self) -> Result<Option<&ElementHeader>, EbmlError> {
+        if self.peeked.is_none() {
+            self.peeked = self.read_header()?;
+        }
+        Ok(self.peeked.as_ref())
+    }
+
+    // Returns the masters currently being read, from the root down
+    fn element_path<S: EbmlSchema + ?Sized>(&self, schema: &S) -> ElementPath {
+        ElementPath::new(&self.path, schema)
+    }
+
+    // Reads the header of the next element, `None` at EOF
+    pub fn next_header(&mut self) -> Result<Option<ElementHeader>, EbmlError> {
+        let start = self.position();
+        self.take_header()
+            .map_err(|error| error.at(start, ElementPath::default()))
+    }
+
+    // Returns the header of the next element without consuming it, `None` at EOF
+    pub fn peek_header(&mut self) -> Result<Option<&ElementHeader>, EbmlError> {
+        let start = self.position();
+        self.peek()
+            .map_err(|error| error.at(start, ElementPath::default()))
+    }
+
+    // Discards bytes until `target` is reached
+    pub fn skip_to(&mut self, target: u64) -> Result<(), EbmlError> {
+        let position = self.position();
+        if target < position || (self.peeked.is_some() && target != position) {
+            return Err(EbmlError::BackwardSeek { position, target });
+        }
+        if self.peeked.is_some() {
+            return Ok(());
+        }
+        let remaining = target - position;
+        let skipped = io::copy(&mut (&mut self.reader).take(remaining), &mut io::sink())?;
+        if skipped < remaining {
+            return Err(EbmlError::UnexpectedEof("skipping element data"));
+        }
+        Ok(())
+    }
+
+    // Reads the data of a known-size element whose header was just read
+    pub fn read_data(&mut self, header: &ElementHeader) -> Result<Vec<u8>, EbmlError> {
+        self.read_payload(header)
+            .map_err(|error| error.at(header.header.start, ElementPath::default()))
+    }
+
+    fn read_payload(&mut self, header: &ElementHeader) -> Result<Vec<u8>, EbmlError> {
+        let Some(size) = header.size else {
+            return Err(EbmlError::UnknownSizeNotAllowed(header.id));
+        };
+        // Sizes not addressable in memory are over any limit as well
+        let num_bytes = usize::try_from(size)
+            .ok()
+            .filter(|_| size <= self.limits.max_payload_size)
+            .ok_or(EbmlError::PayloadTooLarge {
+                size,
+                limit: self.limits.max_payload_size,
+            })?;
+        self.skip_to(header.data_start())?;
+        let mut buf = vec![0u8; num_bytes];
+        self.reader
+            .read_exact(&mut buf)
+            .map_err(|error| error::map_eof_error(error, "reading element data"))?;
+        Ok(buf)
+    }
+
+    // Discards the data of an element whose header was just read
+    // Unknown-size elements are skipped by reading their children
+    pub fn skip_data<S: EbmlSchema + ?Sized>(
+        &mut self,
+        schema: &S,
+        header: &ElementHeader,
+    ) -> Result<(), EbmlError> {
+        match header.data_end() {
+            Some(end) => self.skip_to(end),
+            None => self
+                .read_element_from(schema, header.clone(), None, 0, 0)
+                .map(|_| ())
+                .map_err(|error| error.at(header.header.start, self.element_path(schema))),
+        }
+    }
+
+    // Reads the next element, discarding the data of non-master elements
+    pub fn read_element<S: EbmlSchema + ?Sized>(
+        &mut self,
+        schema: &S,
+    ) -> Result<Option<ParsedElement>, EbmlError> {
+        self.read_root_element(schema, 0)
+    }
+
+    // Reads the next root level element, after `siblings` others
+    fn read_root_element<S: EbmlSchema + ?Sized>(
+        &mut self,
+        schema: &S,
+        siblings: usize,
+    ) -> Result<Option<ParsedElement>, EbmlError> {
+        let start = self.position();
+        let element = match self.take_header() {
+            Ok(Some(header)) => self
+                .read_element_from(schema, header, None, siblings, 0)
+                .map(Some),
+            Ok(None) => Ok(None),
+            Err(error) => Err(error),
+        }
+        .map_err(|error| error.at(start, self.element_path(schema)))?;
+        if let Some((max_id_length, max_size_length)) = self.declared_max_lengths.take() {
+            self.set_max_lengths(max_id_length, max_size_length);
+        }
+        Ok(element)
+    }
+
+    // Reads all remaining elements, discarding the data of non-master elements
+    pub fn read_root<S: EbmlSchema + ?Sized>(
+        &mut self,
+        schema: &S,
+    ) -> Result<Vec<ParsedElement>, EbmlError> {
+        let mut elements = Vec::new();
+        while let Some(element) = self.read_root_element(schema, elements.len())? {
+            elements.push(element);
+        }
+        Ok(elements)
+    }
+
+    // Reads the element of `header`, read after `siblings` others
+    // inside `depth` masters ending at `parent_end`
+    fn read_element_from<S: EbmlSchema + ?Sized>(
+        &mut self,
+        schema: &S,
+        header: ElementHeader,
+        parent_end: Option<u64>,
+        siblings: usize,
+        depth: usize,
+    ) -> Result<ParsedElement, EbmlError> {
+        check_header(schema, &self.limits, &header, parent_end, siblings, depth)?;
+        let data_start = header.data_start();
+        let is_master = schema.is_master(header.id);
+        let Some(size) = header.size else {
+            self.path.push(header.id);
+            let children =
+                self.read_unknown_size_children(schema, header.id, parent_end, depth + 1);
+            self.path.pop();
+            return Ok(ParsedElement {
+                id: header.id,
+                header: header.header,
+                data: ByteRange {
+                    start: data_start,
+                    length: self.position() - data_start,
+                },
+                children: Some(children?),
+                unknown_size: true,
+            });
+        };
+
+        let data = ByteRange {
+            start: data_start,
+            length: size,
+        };
+        let end = data_start + size;
+
+        if !is_master {
+            // The maximum lengths declared by the EBML header are the only data kept
+            if self.path == [EBML_HEADER_ID] && is_max_length_id(header.id) {
+                let data = self.read_payload(&header)?;
+                let current = (self.max_id_length, self.max_size_length);
+                let (max_id_length, max_size_length) =
+                    self.declared_max_lengths.get_or_insert(current);
+                apply_max_length((max_id_length, max_size_length), header.id, data)?;
+            } else {
+                self.skip_to(end)?;
+            }
+            return Ok(ParsedElement {
+                id: header.id,
+                header: header.header,
+                data,
+                children: None,
+                unknown_size: false,
+            });
+        }
+
+        self.path.push(header.id);
+        let children = self.read_known_size_children(schema, end, depth + 1);
+        self.path.pop();
+        Ok(ParsedElement {
+            id: header.id,
+            header: header.header,
+            data,
+            children: Some(children?),
+            unknown_size: false,
+        })
+    }
+
+    // Reads children of a known-size master ending at `end`
+    fn read_known_size_children<S: EbmlSchema + ?Sized>(
+        &mut self,
+        schema: &S,
+        end: u64,
+        depth: usize,
+    ) -> Result<Vec<ParsedElement>, EbmlError> {
+        let mut children = Vec::new();
+        while self.position() < end {
+            let start = self.position();
+            let child = match self.take_header() {
+                Ok(Some(child)) => {
+                    self.read_element_from(schema, child, Some(end), children.len(), depth)
+                }
+                Ok(None) => Err(EbmlError::UnexpectedEof("reading master element children")),
+                Err(error) => Err(error),
+            }
+            .map_err(|error| error.at(start, self.element_path(schema)))?;
+            children.push(child);
+        }
+        Ok(children)
+    }
+
+    // Reads children of an unknown-size master until the end of its parent, EOF,
+    // or an element which per the schema level cannot be a child
+    // The terminating element is left peeked for the caller
+    fn read_unknown_size_children<S: EbmlSchema + ?Sized>(
+        &mut self,
+        schema: &S,
+        id: u64,
+        parent_end: Option<u64>,
+        depth: usize,
+    ) -> Result<Vec<ParsedElement>, EbmlError> {
+        let level = schema.level(id);
+        let mut children = Vec::new();
+
+        loop {
+            if parent_end.is_some_and(|end| self.position() >= end) {
+                break;
+            }
+            let start = self.position();
+            let child = match self.peek() {
+                Ok(None) => break,
+                Ok(Some(next)) if ends_unknown_size(schema, level, next) => break,
+                Ok(Some(_)) => {
+                    let child = self.take_header()?.expect("header was peeked");
+                    self.read_element_from(schema, child, parent_end, children.len(), depth)
+                }
+                Err(error) => Err(error),
+            }
+            .map_err(|error| error.at(start, self.element_path(schema)))?;
+            children.push(child);
+        }
+        Ok(children)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebml::element::Element;
+
+    struct TestSchema;
+
+    impl EbmlSchema for TestSchema {
+        fn is_master(&self, id: u64) -> bool {
+            matches!(id, 0x1A45_DFA3 | 0x1853_8067 | 0x1F43_B675)
+        }
+
+        fn level(&self, id: u64) -> Option<u8> {
+            match id {
+                0x1A45_DFA3 | 0x1853_8067 => Some(0),
+                0x1F43_B675 => Some(1),
+                _ => None,
+            }
+        }
+    }
+
+    // Only implements `Read`, to make sure nothing relies on seeking
+    struct Pipe(Vec<u8>, usize);
+
+    impl Read for Pipe {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = buf.len().min(self.0.len() - self.1).min(3);
+            buf[..n].copy_from_slice(&self.0[self.1..self.1 + n]);
+            self.1 += n;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_stream_read_root() {
+        let bytes = vec![
+            0x18, 0x53, 0x80, 0x67, 0xFF, // Segment, unknown size
+            0x1F, 0x43, 0xB6, 0x75, 0xFF, // Cluster, unknown size
+            0xA3, 0x84, 0x01, 0x02, 0x03, 0x04, // SimpleBlock
+            0x1F, 0x43, 0xB6, 0x75, 0x83, // Cluster, 3 bytes
+            0xE7, 0x81, 0x01, // Timestamp
+        ];
+        let mut reader = EbmlStreamReader::new(Pipe(bytes, 0));
+        let root = reader.read_root(&TestSchema).unwrap();
+        assert_eq!(root.len(), 1);
+        assert!(root[0].unknown_size);
+        assert_eq!(root[0].data.length, 19);
+
+        let clusters = root[0].children.as_ref().unwrap();
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].data.length, 6);
+        assert_eq!(clusters[1].header.start, 16);
+    }
+
+    #[test]
+    fn test_stream_read_data() {
+        let bytes = Element::Root {
+            children: vec![
+                Element::Raw {
+                    id: 0xEC,
+                    data: vec![0; 10],
+                },
+                Element::Raw {
+                    id: 0x4282,
+                    data: b"webm".to_vec(),
+                },
+            ],
+        }
+        .to_bytes()
+        .unwrap();
+        let mut reader = EbmlStreamReader::new(Pipe(bytes, 0));
+
+        let void = reader.next_header().unwrap().unwrap();
+        reader.skip_data(&TestSchema, &void).unwrap();
+
+        assert_eq!(reader.peek_header().unwrap().unwrap().id, 0x4282);
+        let doctype = reader.next_header().unwrap().unwrap();
+        assert_eq!(reader.read_data(&doctype).unwrap(), b"webm");
+        assert!(reader.next_header().unwrap().is_none());
+        let error = reader.read_data(&void).unwrap_err();
+        assert!(matches!(error.kind(), EbmlError::BackwardSeek { .. }));
+        assert_eq!(error.offset(), Some(void.header.start));
+    }
+
+    #[test]
+    fn test_stream_limits() {
+        let limits = ReaderLimits {
+            max_payload_size: 4,
+            max_depth: 1,
+            ..ReaderLimits::default()
+        };
+
+        let bytes = vec![0x42, 0x82, 0xC0, 0x00]; // DocType, 64 bytes
+        let mut reader = EbmlStreamReader::with_limits(Pipe(bytes, 0), limits);
+        let header = reader.next_header().unwrap().unwrap();
+        let error = reader.read_data(&header).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            EbmlError::PayloadTooLarge { size: 64, limit: 4 }
+        ));
+
+        let bytes = vec![
+            0x18, 0x53, 0x80, 0x67, 0xFF, // Segment, unknown size
+            0x1F, 0x43, 0xB6, 0x75, 0xFF, // Cluster, unknown size
+        ];
+        let mut reader = EbmlStreamReader::with_limits(Pipe(bytes, 0), limits);
+        let error = reader.read_root(&TestSchema).unwrap_err();
+        assert!(matches!(error.kind(), EbmlError::DepthLimitExceeded(1)));
+        assert_eq!(error.offset(), Some(5));
+    }
+
+    #[test]
+    fn test_stream_header_max_lengths() {
+        let header = |max_size_length: Vec<u8>| Element::Master {
+            id: 0x1A45_DFA3,
+            children: vec![Element::Raw {
+                id: 0x42F3,
+                data: max_size_length,
+            }],
+        };
+        let mut bytes = header(vec![0x04]).to_bytes().unwrap();
+        bytes.extend([0xEC, 0x10, 0x00, 0x00, 0x00]); // Void, 4-byte size
+        let mut reader = EbmlStreamReader::new(Pipe(bytes.clone(), 0));
+        assert_eq!(reader.read_root(&TestSchema).unwrap().len(), 2);
+
+        bytes.extend([0xEC, 0x01, 0, 0, 0, 0, 0, 0, 0]); // Void, 8-byte size
+        let mut reader = EbmlStreamReader::new(Pipe(bytes, 0));
+        let error = reader.read_root(&TestSchema).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            EbmlError::SizeTooLong { length: 8, max: 4 }
+        ));
+
+        let bytes = header(vec![0x09]).to_bytes().unwrap();
+        let mut reader = EbmlStreamReader::new(Pipe(bytes, 0));
+        let error = reader.read_root(&TestSchema).unwrap_err();
+        assert!(matches!(error.kind(), EbmlError::InvalidMaxLength(0x42F3)));
+        assert_eq!(error.path().unwrap().to_string(), "0x1A45DFA3");
+    }
+
+    #[test]
+    fn test_stream_element_overrun() {
+        let bytes = vec![
+            0x18, 0x53, 0x80, 0x67, 0x83, // Segment, 3 bytes
+            0x42, 0x82, 0x85, b'h', b'e', b'l', b'l', b'o', // DocType, 5 bytes
+        ];
+        let mut reader = EbmlStreamReader::new(Pipe(bytes, 0));
+        let error = reader.read_root(&TestSchema).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            EbmlError::ElementOverrun {
+                id: 0x4282,
+                position: 5,
+                parent_end: 8,
+            }
+        ));
+        assert_eq!(error.offset(), Some(5));
+    }
+}
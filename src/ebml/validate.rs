@@ -0,0 +1,206 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::ebml::error::EbmlError;
+use crate::ebml::primitives::{Value, ValueError, parse_value};
+use crate::ebml::reader::{EbmlReader, EbmlSchema, ParsedElement};
+use crate::ebml::source::ByteSource;
+
+#[derive(Debug)]
+pub enum ValidationIssueKind {
+    // The element is not defined by the schema
+    UnknownElement,
+    // The element is not allowed in its parent, `None` at the root level
+    UnexpectedParent { parent: Option<u64> },
+    // A mandatory element without a default value occurs fewer times than required
+    TooFewOccurrences { count: usize, min: u32 },
+    TooManyOccurrences { count: usize, max: u32 },
+    UnknownSizeNotAllowed,
+    ValueOutOfRange(Value),
+    InvalidValue(ValueError),
+}
+
+// A violation of the schema found by `validate`
+#[derive(Debug)]
+pub struct ValidationIssue {
+    pub id: u64,
+    // Start of the element header, or of the parent header for missing elements
+    // (0 for missing root elements)
+    pub position: u64,
+    pub kind: ValidationIssueKind,
+}
+
+// Checks `elements`, e.g. the result of `read_root`, and all their descendants against the
+// parents, occurrences, ranges and unknown sizes allowed by `schema`
+// Only elements with a range have their data read
+pub fn validate<S: EbmlSchema + ?Sized, R: ByteSource>(
+    reader: &mut EbmlReader<R>,
+    schema: &S,
+    elements: &[ParsedElement],
+) -> Result<Vec<ValidationIssue>, EbmlError> {
+    let mut issues = Vec::new();
+    validate_children(reader, schema, None, elements, 0, &mut issues)?;
+    Ok(issues)
+}
+
+fn validate_children<S: EbmlSchema + ?Sized, R: ByteSource>(
+    reader: &mut EbmlReader<R>,
+    schema: &S,
+    parent: Option<&ParsedElement>,
+    children: &[ParsedElement],
+    depth: usize,
+    issues: &mut Vec<ValidationIssue>,
+) -> Result<(), EbmlError> {
+    let mut counts = HashMap::new();
+    for child in children {
+        *counts.entry(child.id).or_insert(0) += 1;
+        validate_element(reader, schema, parent, child, depth, issues)?;
+    }
+
+    // Occurrences can only be checked in parents known to the schema
+    let parent_path = match parent.map(|parent| schema.definition(parent.id)) {
+        Some(Some(parent_def)) => Some(parent_def.path.as_ref()),
+        Some(None) => return Ok(()),
+        None => None,
+    };
+    let position = parent.map_or(0, |parent| parent.header.start);
+    for element in schema.definitions() {
+        let count = counts.get(&element.id).copied().unwrap_or(0);
+        let expected = !element.is_global()
+            && match parent_path {
+                Some(parent_path) => element.parent_path() == Some(parent_path),
+                None => element.level() == Some(0),
+            };
+        if expected && count < element.min_occurs as usize && element.default.is_none() {
+            issues.push(ValidationIssue {
+                id: element.id,
+                position,
+                kind: ValidationIssueKind::TooFewOccurrences {
+                    count,
+                    min: element.min_occurs,
+                },
+            });
+        }
+        if let Some(max) = element.max_occurs
+            && count > max as usize
+        {
+            issues.push(ValidationIssue {
+                id: element.id,
+                position,
+                kind: ValidationIssueKind::TooManyOccurrences { count, max },
+            });
+        }
+    }
+    Ok(())
+}
+
+fn validate_element<S: EbmlSchema + ?Sized, R: ByteSource>(
+    reader: &mut EbmlReader<R>,
+    schema: &S,
+    parent: Option<&ParsedElement>,
+    element: &ParsedElement,
+    depth: usize,
+    issues: &mut Vec<ValidationIssue>,
+) -> Result<(), EbmlError> {
+    let mut report = |kind| {
+        issues.push(ValidationIssue {
+            id: element.id,
+            position: element.header.start,
+            kind,
+        });
+    };
+
+    let Some(def) = schema.definition(element.id) else {
+        report(ValidationIssueKind::UnknownElement);
+        return Ok(());
+    };
+    // Elements in parents unknown to the schema cannot be checked for their parent
+    let parent_def = parent.and_then(|parent| schema.definition(parent.id));
+    if parent.is_none_or(|_| parent_def.is_some()) && !def.allows_parent(parent_def, depth) {
+        report(ValidationIssueKind::UnexpectedParent {
+            parent: parent.map(|parent| parent.id),
+        });
+    }
+    if element.unknown_size && !def.unknown_size_allowed {
+        report(ValidationIssueKind::UnknownSizeNotAllowed);
+    }
+
+    // Empty elements take the default value, which is not checked against the range
+    if def.range.is_some()
+        && element.children.is_none()
+        && !(element.data.length == 0 && def.default.is_some())
+    {
+        let data = reader.read_range(&element.data).map(Cow::into_owned)?;
+        match parse_value(def.element_type, data) {
+            Ok(Some(value)) if !def.in_range(&value) => {
+                report(ValidationIssueKind::ValueOutOfRange(value));
+            }
+            Ok(_) => {}
+            Err(error) => report(ValidationIssueKind::InvalidValue(error)),
+        }
+    }
+
+    if let Some(children) = &element.children {
+        validate_children(reader, schema, Some(element), children, depth + 1, issues)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebml::reader::read_root;
+    use crate::ebml::writer::EbmlWriter;
+    use crate::matroska::MatroskaSchema;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_validate() {
+        let mut writer = EbmlWriter::new(Cursor::new(Vec::new()));
+        writer.start_master(0x1853_8067).unwrap(); // Segment
+        writer.start_master(0x1549_A966).unwrap(); // Info
+        writer.write_element(0x002A_D7B1, &[0x00]).unwrap(); // TimestampScale, out of range
+        writer.write_element(0x4D80, b"a").unwrap(); // MuxingApp
+        writer.write_element(0x4D80, b"b").unwrap(); // MuxingApp, twice
+        writer.write_element(0xE7, &[0x00]).unwrap(); // Cluster Timestamp, wrong parent
+        writer.write_element(0xBF, &[0; 4]).unwrap(); // CRC-32
+        writer.end_master().unwrap();
+        writer.end_master().unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+
+        let mut reader = EbmlReader::new(Cursor::new(bytes));
+        let root = read_root(&mut reader, &MatroskaSchema).unwrap();
+        let issues = validate(&mut reader, &MatroskaSchema, &root).unwrap();
+        let kinds: Vec<_> = issues.iter().map(|issue| (issue.id, &issue.kind)).collect();
+
+        assert!(matches!(
+            kinds[..],
+            [
+                (
+                    0x002A_D7B1,
+                    ValidationIssueKind::ValueOutOfRange(Value::UnsignedInteger(0))
+                ),
+                (
+                    0xE7,
+                    ValidationIssueKind::UnexpectedParent {
+                        parent: Some(0x1549_A966)
+                    }
+                ),
+                (
+                    0x4D80,
+                    ValidationIssueKind::TooManyOccurrences { count: 2, max: 1 }
+                ),
+                (
+                    0x5741,
+                    ValidationIssueKind::TooFewOccurrences { count: 0, min: 1 }
+                ),
+                (
+                    0x1A45_DFA3,
+                    ValidationIssueKind::TooFewOccurrences { count: 0, min: 1 }
+                ),
+            ]
+        ));
+        // Missing elements are reported at their parent, after the 12-byte Segment header
+        assert_eq!(issues[3].position, 12);
+    }
+}
@@ -0,0 +1,214 @@
+use crate::ebml::cursor::EbmlCursor;
+use crate::ebml::error::EbmlError;
+use crate::ebml::reader::{ByteRange, EbmlSchema, ParsedElement};
+use crate::ebml::source::ByteSource;
+
+// An element as seen by an `EbmlVisitor`
+#[derive(Debug, Clone)]
+pub struct VisitedElement {
+    // Number of enclosing master elements, 0 at the level where the walk started
+    pub depth: usize,
+    pub id: u64,
+    pub header: ByteRange,
+    // For unknown-size masters walked by `walk_cursor`, `data.length` is 0 in `enter`
+    // and only known in `leave`, after the children have been read
+    pub data: ByteRange,
+    pub unknown_size: bool,
+}
+
+impl VisitedElement {
+    fn from_parsed(element: &ParsedElement, depth: usize) -> Self {
+        Self {
+            depth,
+            id: element.id,
+            header: element.header.clone(),
+            data: element.data.clone(),
+            unknown_size: element.unknown_size,
+        }
+    }
+}
+
+// Callbacks for walking an element tree, see `walk_tree` and `walk_cursor`
+// Masters get `enter` before and `leave` after their children, other elements get `leaf`
+pub trait EbmlVisitor {
+    // Returns whether to visit the children of the master, `leave` is only called if so
+    fn enter(&mut self, _element: &VisitedElement) -> bool {
+        true
+    }
+
+    fn leave(&mut self, _element: &VisitedElement) {}
+
+    fn leaf(&mut self, _element: &VisitedElement) {}
+}
+
+// Walks `elements` and their descendants in file order
+// Elements with `children` are treated as masters
+pub fn walk_tree<V: EbmlVisitor + ?Sized>(elements: &[ParsedElement], visitor: &mut V) {
+    walk_tree_at(elements, 0, visitor);
+}
+
+fn walk_tree_at<V: EbmlVisitor + ?Sized>(
+    elements: &[ParsedElement],
+    depth: usize,
+    visitor: &mut V,
+) {
+    for element in elements {
+        let visited = VisitedElement::from_parsed(element, depth);
+        match &element.children {
+            Some(children) => {
+                if visitor.enter(&visited) {
+                    walk_tree_at(children, depth + 1, visitor);
+                    visitor.leave(&visited);
+                }
+            }
+            None => visitor.leaf(&visited),
+        }
+    }
+}
+
+// Walks the remaining elements at the current level of `cursor` and their descendants,
+// reading headers as it goes without building a tree
+// Children of masters for which `enter` returns false are skipped without being read
+pub fn walk_cursor<S: EbmlSchema + ?Sized, R: ByteSource, V: EbmlVisitor + ?Sized>(
+    cursor: &mut EbmlCursor<'_, S, R>,
+    visitor: &mut V,
+) -> Result<(), EbmlError> {
+    let base_depth = cursor.depth();
+    walk_cursor_at(cursor, base_depth, visitor)
+}
+
+fn walk_cursor_at<S: EbmlSchema + ?Sized, R: ByteSource, V: EbmlVisitor + ?Sized>(
+    cursor: &mut EbmlCursor<'_, S, R>,
+    base_depth: usize,
+    visitor: &mut V,
+) -> Result<(), EbmlError> {
+    // Skipping the current element is left to `next_element`
+    while let Some(header) = cursor.next_element()? {
+        let mut visited = VisitedElement {
+            depth: cursor.depth() - base_depth,
+            id: header.id,
+            header: header.header.clone(),
+            data: ByteRange {
+                start: header.data_start(),
+                length: header.size.unwrap_or(0),
+            },
+            unknown_size: header.size.is_none(),
+        };
+        if !cursor.schema().is_master(header.id) {
+            visitor.leaf(&visited);
+            continue;
+        }
+        if visitor.enter(&visited) {
+            cursor.enter()?;
+            walk_cursor_at(cursor, base_depth, visitor)?;
+            cursor.leave()?;
+            visited.data.length = cursor.position()? - visited.data.start;
+            visitor.leave(&visited);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebml::element::{EbmlId, EbmlSize, Element};
+    use crate::ebml::reader::{EbmlReader, read_root};
+    use std::io::Cursor;
+
+    struct TestSchema;
+
+    impl EbmlSchema for TestSchema {
+        fn is_master(&self, id: u64) -> bool {
+            matches!(id, 0x1853_8067 | 0x1549_A966 | 0x1F43_B675)
+        }
+
+        fn level(&self, id: u64) -> Option<u8> {
+            match id {
+                0x1853_8067 => Some(0),
+                0x1549_A966 | 0x1F43_B675 => Some(1),
+                _ => None,
+            }
+        }
+    }
+
+    // Records the callbacks as strings, skipping the children of Info (0x1549A966)
+    #[derive(Default)]
+    struct Recorder {
+        events: Vec<String>,
+    }
+
+    impl EbmlVisitor for Recorder {
+        fn enter(&mut self, element: &VisitedElement) -> bool {
+            self.events.push(format!(
+                "enter {:X} {} {}",
+                element.id, element.depth, element.header.start
+            ));
+            element.id != 0x1549_A966
+        }
+
+        fn leave(&mut self, element: &VisitedElement) {
+            self.events.push(format!(
+                "leave {:X} {}",
+                element.id,
+                element.data.start + element.data.length
+            ));
+        }
+
+        fn leaf(&mut self, element: &VisitedElement) {
+            self.events.push(format!(
+                "leaf {:X} {} {}",
+                element.id, element.depth, element.data.length
+            ));
+        }
+    }
+
+    // Segment with an unknown size, containing Info and a Cluster
+    fn sample_bytes() -> Vec<u8> {
+        let mut bytes = EbmlId::new(0x1853_8067).to_bytes();
+        bytes.extend(EbmlSize::unknown(1).to_bytes());
+        for child in [
+            Element::Master {
+                id: 0x1549_A966,
+                children: vec![Element::Raw {
+                    id: 0x4D80,
+                    data: b"mkvedit".to_vec(),
+                }],
+            },
+            Element::Master {
+                id: 0x1F43_B675,
+                children: vec![Element::Raw {
+                    id: 0xE7,
+                    data: vec![0x00],
+                }],
+            },
+        ] {
+            bytes.extend(child.to_bytes().unwrap());
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_walk_tree_and_cursor() {
+        let expected = vec![
+            "enter 18538067 0 0",
+            "enter 1549A966 1 5",
+            "enter 1F43B675 1 20",
+            "leaf E7 2 1",
+            "leave 1F43B675 28",
+            "leave 18538067 28",
+        ];
+
+        let mut reader = EbmlReader::new(Cursor::new(sample_bytes()));
+        let root = read_root(&mut reader, &TestSchema).unwrap();
+        let mut recorder = Recorder::default();
+        walk_tree(&root, &mut recorder);
+        assert_eq!(recorder.events, expected);
+
+        let mut reader = EbmlReader::new(Cursor::new(sample_bytes()));
+        let mut cursor = EbmlCursor::new(&mut reader, &TestSchema);
+        let mut recorder = Recorder::default();
+        walk_cursor(&mut cursor, &mut recorder).unwrap();
+        assert_eq!(recorder.events, expected);
+    }
+}
@@ -0,0 +1,246 @@
+use std::io::{Seek, SeekFrom, Write};
+
+use crate::ebml::element::{EbmlId, EbmlSize};
+use crate::ebml::error::EbmlError;
+use crate::ebml::reader::{ByteRange, ParsedElement};
+
+// Void element, allowed anywhere to reserve space which can later be reused when editing in place
+pub const VOID_ID: u64 = 0xEC;
+
+// Returns the header (ID and size) of a Void element whose total length,
+// including the header, is exactly `length` bytes
+// Any length of at least 2 bytes is possible, as the size is widened when the data
+// would otherwise not fill the gap, e.g. 129 bytes use a 2-byte size and 126 bytes of data
+pub fn void_header(length: u64) -> Result<Vec<u8>, EbmlError> {
+    let id = EbmlId::new(VOID_ID).to_bytes();
+    let data_length =
+        |size_length: u8| length.checked_sub(id.len() as u64 + u64::from(size_length));
+    let size = (1..=8)
+        .find_map(|size_length| EbmlSize::with_length(data_length(size_length)?, size_length).ok())
+        .ok_or(EbmlError::InvalidVoidLength(length))?;
+
+    let mut header = id;
+    header.extend(size.to_bytes());
+    Ok(header)
+}
+
+// Returns a complete zero-filled Void element of exactly `length` bytes
+pub fn void_bytes(length: u64) -> Result<Vec<u8>, EbmlError> {
+    let mut bytes = void_header(length)?;
+    bytes.resize(
+        usize::try_from(length).map_err(|_| EbmlError::InvalidVoidLength(length))?,
+        0,
+    );
+    Ok(bytes)
+}
+
+// Writes the header of a Void element of exactly `length` bytes at `start`
+// The rest of the region is left as is, as the contents of a Void element are ignored
+pub fn write_void_at<W: Write + Seek>(
+    writer: &mut W,
+    start: u64,
+    length: u64,
+) -> Result<(), EbmlError> {
+    let header = void_header(length)?;
+    writer.seek(SeekFrom::Start(start))?;
+    writer.write_all(&header)?;
+    Ok(())
+}
+
+// Shrinks the free space `range` by `length` bytes at its start,
+// e.g. to make room for the element before it to grow, and returns the remaining free space
+// Fails if exactly one byte would remain, which cannot hold a Void element
+pub fn shrink_void<W: Write + Seek>(
+    writer: &mut W,
+    range: &ByteRange,
+    length: u64,
+) -> Result<ByteRange, EbmlError> {
+    let remaining = range
+        .length
+        .checked_sub(length)
+        .ok_or(EbmlError::NotEnoughFreeSpace {
+            needed: length,
+            available: range.length,
+        })?;
+    let start = range.start + length;
+    if remaining > 0 {
+        write_void_at(writer, start, remaining)?;
+    }
+    Ok(ByteRange {
+        start,
+        length: remaining,
+    })
+}
+
+// Grows the free space `range` by `length` bytes at its start,
+// e.g. after the element before it shrank, and returns the new free space
+// Fails if there are less than `length` bytes before `range`
+pub fn grow_void<W: Write + Seek>(
+    writer: &mut W,
+    range: &ByteRange,
+    length: u64,
+) -> Result<ByteRange, EbmlError> {
+    let start = range
+        .start
+        .checked_sub(length)
+        .ok_or(EbmlError::NotEnoughFreeSpace {
+            needed: length,
+            available: range.start,
+        })?;
+    let grown = ByteRange {
+        start,
+        length: range.length + length,
+    };
+    write_void_at(writer, grown.start, grown.length)?;
+    Ok(grown)
+}
+
+// Free space inside a master element, made up of one or more adjacent Void elements
+#[derive(Debug, Clone)]
+pub struct FreeSpace {
+    // `None` for Void elements at the root level
+    pub parent: Option<u64>,
+    // The Void elements including their headers
+    pub range: ByteRange,
+}
+
+// Lists the free space in `elements` and all their descendants,
+// merging adjacent Void elements into a single range
+pub fn free_space(elements: &[ParsedElement]) -> Vec<FreeSpace> {
+    let mut spaces = Vec::new();
+    collect_free_space(None, elements, &mut spaces);
+    spaces
+}
+
+fn collect_free_space(
+    parent: Option<u64>,
+    elements: &[ParsedElement],
+    spaces: &mut Vec<FreeSpace>,
+) {
+    let mut current: Option<ByteRange> = None;
+    for element in elements {
+        if element.id == VOID_ID {
+            let end = element.data.start + element.data.length;
+            match &mut current {
+                Some(range) if range.start + range.length == element.header.start => {
+                    range.length = end - range.start;
+                }
+                _ => {
+                    if let Some(range) = current.take() {
+                        spaces.push(FreeSpace { parent, range });
+                    }
+                    current = Some(ByteRange {
+                        start: element.header.start,
+                        length: end - element.header.start,
+                    });
+                }
+            }
+            continue;
+        }
+        if let Some(range) = current.take() {
+            spaces.push(FreeSpace { parent, range });
+        }
+        if let Some(children) = &element.children {
+            collect_free_space(Some(element.id), children, spaces);
+        }
+    }
+    if let Some(range) = current {
+        spaces.push(FreeSpace { parent, range });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebml::element::Element;
+    use crate::ebml::reader::{EbmlReader, EbmlSchema, read_root};
+    use std::io::Cursor;
+
+    struct TestSchema;
+
+    impl EbmlSchema for TestSchema {
+        fn is_master(&self, id: u64) -> bool {
+            id == 0x1853_8067
+        }
+    }
+
+    #[test]
+    fn test_void_header() {
+        assert!(matches!(
+            void_header(1),
+            Err(EbmlError::InvalidVoidLength(1))
+        ));
+        assert_eq!(void_header(2).unwrap(), vec![0xEC, 0x80]);
+        assert_eq!(void_header(128).unwrap(), vec![0xEC, 0xFE]);
+        // 127 bytes of data would need the reserved all-ones 1-byte size
+        assert_eq!(void_header(129).unwrap(), vec![0xEC, 0x40, 0x7E]);
+        assert_eq!(void_header(130).unwrap(), vec![0xEC, 0x40, 0x7F]);
+
+        for length in (2..300).chain(16_380..16_390) {
+            let bytes = void_bytes(length).unwrap();
+            assert_eq!(bytes.len() as u64, length);
+            let mut reader = EbmlReader::new(Cursor::new(bytes));
+            let root = read_root(&mut reader, &TestSchema).unwrap();
+            assert_eq!(root.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_free_space() {
+        let bytes = Element::Root {
+            children: vec![
+                Element::Master {
+                    id: 0x1853_8067,
+                    children: vec![
+                        Element::Raw {
+                            id: 0x4282,
+                            data: b"webm".to_vec(),
+                        },
+                        Element::Void { length: 10 },
+                        Element::Void { length: 129 },
+                    ],
+                },
+                Element::Void { length: 2 },
+            ],
+        }
+        .to_bytes()
+        .unwrap();
+        let mut reader = EbmlReader::new(Cursor::new(bytes));
+        let root = read_root(&mut reader, &TestSchema).unwrap();
+
+        let spaces = free_space(&root);
+        assert_eq!(spaces.len(), 2);
+        assert_eq!(spaces[0].parent, Some(0x1853_8067));
+        assert_eq!(spaces[0].range.start, 13);
+        assert_eq!(spaces[0].range.length, 139);
+        assert_eq!(spaces[1].parent, None);
+        assert_eq!(spaces[1].range.length, 2);
+    }
+
+    #[test]
+    fn test_shrink_grow_void() {
+        let mut file = Cursor::new(void_bytes(100).unwrap());
+        let range = ByteRange {
+            start: 0,
+            length: 100,
+        };
+
+        let shrunk = shrink_void(&mut file, &range, 10).unwrap();
+        assert_eq!(shrunk.start, 10);
+        assert_eq!(file.get_ref()[10..12], [0xEC, 0xD8]);
+        assert!(matches!(
+            shrink_void(&mut file, &shrunk, 89),
+            Err(EbmlError::InvalidVoidLength(1))
+        ));
+
+        let grown = grow_void(&mut file, &shrunk, 4).unwrap();
+        assert_eq!(grown.start, 6);
+        assert_eq!(grown.length, 94);
+        let root = read_root(
+            &mut EbmlReader::new(Cursor::new(file.get_ref()[6..].to_vec())),
+            &TestSchema,
+        )
+        .unwrap();
+        assert_eq!(root[0].data.length, 92);
+    }
+}
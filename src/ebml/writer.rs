@@ -0,0 +1,322 @@
+use std::io::{Seek, SeekFrom, Write};
+
+use crc32fast::Hasher;
+
+use crate::ebml::crc::CRC32_HEADER;
+use crate::ebml::element::{EbmlId, EbmlSize, Element};
+use crate::ebml::error::EbmlError;
+use crate::ebml::void::void_header;
+
+// Default number of bytes reserved for the size of a master element,
+// large enough for any size representable as a VINT
+const DEFAULT_MASTER_SIZE_LENGTH: u8 = 8;
+
+// A master element which has been started but not yet ended
+struct OpenMaster {
+    id: u64,
+    size_position: u64,
+    size_length: u8,
+    data_start: u64,
+    // Position of the value of the CRC-32 child, if the master has one
+    crc_position: Option<u64>,
+    // CRC-32 of the data written so far (after the CRC-32 child, if any),
+    // kept only when this master or one of its ancestors has a CRC-32 child
+    hasher: Option<Hasher>,
+}
+
+// Streaming EBML writer
+// Children of a master element are written directly to the output,
+// and the size of the master is patched in when the master is ended
+pub struct EbmlWriter<W: Write + Seek> {
+    writer: W,
+    master_size_length: u8,
+    open_masters: Vec<OpenMaster>,
+    // IDs of masters which get a CRC-32 element as their first child
+    crc_ids: Vec<u64>,
+}
+
+impl<W: Write + Seek> EbmlWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            master_size_length: DEFAULT_MASTER_SIZE_LENGTH,
+            open_masters: Vec::new(),
+            crc_ids: Vec::new(),
+        }
+    }
+
+    // Creates a writer which reserves `size_length` bytes for the size of each master element
+    // Ending a master whose data does not fit in `size_length` bytes fails with `SizeTooLarge`
+    pub fn with_master_size_length(writer: W, size_length: u8) -> Result<Self, EbmlError> {
+        // Validate that the length can hold at least an empty master
+        EbmlSize::with_length(0, size_length)?;
+        Ok(Self {
+            writer,
+            master_size_length: size_length,
+            open_masters: Vec::new(),
+            crc_ids: Vec::new(),
+        })
+    }
+
+    pub fn position(&mut self) -> Result<u64, EbmlError> {
+        self.writer.stream_position().map_err(EbmlError::from)
+    }
+
+    // Returns the number of master elements currently open
+    pub fn depth(&self) -> usize {
+        self.open_masters.len()
+    }
+
+    // Makes every master element with `id` started afterwards, including those written
+    // through `write`, get a CRC-32 element as its first child
+    pub fn insert_crc(&mut self, id: u64) {
+        if !self.crc_ids.contains(&id) {
+            self.crc_ids.push(id);
+        }
+    }
+
+    // Writes the ID of a master element and reserves space for its size
+    pub fn start_master(&mut self, id: u64) -> Result<(), EbmlError> {
+        self.start_master_with_size_length(id, self.master_size_length)
+    }
+
+    // Same as `start_master`, reserving `size_length` bytes for the size of this master only
+    pub fn start_master_with_size_length(
+        &mut self,
+        id: u64,
+        size_length: u8,
+    ) -> Result<(), EbmlError> {
+        // Placeholder until the size is known, validated here to fail early
+        let placeholder = EbmlSize::with_length(0, size_length)?;
+
+        self.writer.write_all(&EbmlId::new(id).to_bytes())?;
+        let size_position = self.position()?;
+        self.writer.write_all(&placeholder.to_bytes())?;
+        let data_start = self.position()?;
+
+        // The CRC-32 value is written when the master is ended
+        let crc_position = if self.crc_ids.contains(&id) {
+            self.writer.write_all(&CRC32_HEADER)?;
+            self.writer.write_all(&[0; 4])?;
+            Some(data_start + CRC32_HEADER.len() as u64)
+        } else {
+            None
+        };
+        let parent_hashed = self
+            .open_masters
+            .last()
+            .is_some_and(|parent| parent.hasher.is_some());
+
+        self.open_masters.push(OpenMaster {
+            id,
+            size_position,
+            size_length,
+            data_start,
+            crc_position,
+            hasher: (crc_position.is_some() || parent_hashed).then(Hasher::new),
+        });
+        Ok(())
+    }
+
+    // Ends the most recently started master element, patching its size
+    pub fn end_master(&mut self) -> Result<(), EbmlError> {
+        let master = self.open_masters.pop().ok_or(EbmlError::NoOpenMaster)?;
+        let end = self.position()?;
+        let size = EbmlSize::with_length(end - master.data_start, master.size_length)?;
+        let size_bytes = size.to_bytes();
+
+        self.writer.seek(SeekFrom::Start(master.size_position))?;
+        self.writer.write_all(&size_bytes)?;
+        let crc = master.hasher.clone().map(Hasher::finalize);
+        if let (Some(crc_position), Some(crc)) = (master.crc_position, crc) {
+            self.writer.seek(SeekFrom::Start(crc_position))?;
+            self.writer.write_all(&crc.to_le_bytes())?;
+        }
+        self.writer.seek(SeekFrom::Start(end))?;
+
+        // The size (and CRC-32) of the master are only known now, so the parent hashes
+        // its header here and combines it with the hash of its data
+        if let (Some(hasher), Some(parent_hasher)) = (
+            master.hasher,
+            self.open_masters
+                .last_mut()
+                .and_then(|parent| parent.hasher.as_mut()),
+        ) {
+            parent_hasher.update(&EbmlId::new(master.id).to_bytes());
+            parent_hasher.update(&size_bytes);
+            if let Some(crc) = crc.filter(|_| master.crc_position.is_some()) {
+                parent_hasher.update(&CRC32_HEADER);
+                parent_hasher.update(&crc.to_le_bytes());
+            }
+            parent_hasher.combine(&hasher);
+        }
+        Ok(())
+    }
+
+    // Writes bytes belonging to the innermost open master, keeping its CRC-32 up to date
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), EbmlError> {
+        self.writer.write_all(bytes)?;
+        if let Some(hasher) = self
+            .open_masters
+            .last_mut()
+            .and_then(|master| master.hasher.as_mut())
+        {
+            hasher.update(bytes);
+        }
+        Ok(())
+    }
+
+    // Writes a complete non-master element
+    pub fn write_element(&mut self, id: u64, data: &[u8]) -> Result<(), EbmlError> {
+        self.write_bytes(&EbmlId::new(id).to_bytes())?;
+        self.write_bytes(&EbmlSize::new(data.len() as u64).to_bytes())?;
+        self.write_bytes(data)
+    }
+
+    // Writes a zero-filled Void element of exactly `length` bytes including its header
+    pub fn write_void(&mut self, length: u64) -> Result<(), EbmlError> {
+        let header = void_header(length)?;
+        self.write_bytes(&header)?;
+        let mut remaining = length - header.len() as u64;
+        let zeros = [0u8; 4096];
+        while remaining > 0 {
+            let n = remaining.min(zeros.len() as u64);
+            self.write_bytes(&zeros[..usize::try_from(n).unwrap_or(zeros.len())])?;
+            remaining -= n;
+        }
+        Ok(())
+    }
+
+    // Writes an `Element` tree, streaming master elements through `start_master`/`end_master`
+    pub fn write(&mut self, element: &Element) -> Result<(), EbmlError> {
+        match element {
+            Element::Raw { id, data } => self.write_element(*id, data),
+            Element::Master { id, children } => {
+                self.start_master(*id)?;
+                for child in children {
+                    self.write(child)?;
+                }
+                self.end_master()
+            }
+            Element::Root { children } => {
+                for child in children {
+                    self.write(child)?;
+                }
+                Ok(())
+            }
+            Element::Void { length } => self.write_void(*length),
+        }
+    }
+
+    pub fn flush(&mut self) -> Result<(), EbmlError> {
+        self.writer.flush().map_err(EbmlError::from)
+    }
+
+    // Flushes and returns the underlying writer
+    // Fails if any master element has not been ended
+    pub fn finish(mut self) -> Result<W, EbmlError> {
+        if !self.open_masters.is_empty() {
+            return Err(EbmlError::UnclosedMaster(self.open_masters.len()));
+        }
+        self.flush()?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ebml::crc::{CRC32_ID, verify_crcs};
+    use crate::ebml::reader::{EbmlReader, EbmlSchema, read_root};
+    use std::io::Cursor;
+
+    fn sample_tree() -> Element {
+        Element::Master {
+            id: 0x1A45_DFA3,
+            children: vec![
+                Element::Raw {
+                    id: 0x4286,
+                    data: vec![0x01],
+                },
+                Element::Master {
+                    id: 0x4DBB,
+                    children: vec![Element::Raw {
+                        id: 0x53AB,
+                        data: vec![0x15, 0x49, 0xA9, 0x66],
+                    }],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_write_matches_to_bytes() {
+        let mut writer = EbmlWriter::with_master_size_length(Cursor::new(Vec::new()), 1).unwrap();
+        writer.write(&sample_tree()).unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+        assert_eq!(bytes, sample_tree().to_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_write_default_size_length() {
+        let mut writer = EbmlWriter::new(Cursor::new(Vec::new()));
+        writer.start_master(0x1A45_DFA3).unwrap();
+        writer.write_element(0x4286, &[0x01]).unwrap();
+        writer.end_master().unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+        assert_eq!(
+            bytes,
+            vec![
+                0x1A, 0x45, 0xDF, 0xA3, // ID
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, // Size, 8 bytes
+                0x42, 0x86, 0x81, 0x01, // Child
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_size_too_large() {
+        let mut writer = EbmlWriter::new(Cursor::new(Vec::new()));
+        writer
+            .start_master_with_size_length(0x1A45_DFA3, 1)
+            .unwrap();
+        writer.write_element(0xEC, &[0u8; 200]).unwrap();
+        assert!(matches!(
+            writer.end_master(),
+            Err(EbmlError::SizeTooLarge { length: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_write_crc() {
+        struct TestSchema;
+
+        impl EbmlSchema for TestSchema {
+            fn is_master(&self, id: u64) -> bool {
+                matches!(id, 0x1A45_DFA3 | 0x4DBB)
+            }
+        }
+
+        let mut writer = EbmlWriter::new(Cursor::new(Vec::new()));
+        writer.insert_crc(0x1A45_DFA3);
+        writer.insert_crc(0x4DBB);
+        writer.write(&sample_tree()).unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+        assert_eq!(bytes[12..14], CRC32_HEADER);
+
+        let mut reader = EbmlReader::new(Cursor::new(bytes));
+        let root = read_root(&mut reader, &TestSchema).unwrap();
+        let children = root[0].children.as_ref().unwrap();
+        assert_eq!(children[0].id, CRC32_ID);
+        assert_eq!(children[2].children.as_ref().unwrap()[0].id, CRC32_ID);
+        assert!(verify_crcs(&mut reader, &root).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_write_unbalanced_masters() {
+        let mut writer = EbmlWriter::new(Cursor::new(Vec::new()));
+        assert!(matches!(writer.end_master(), Err(EbmlError::NoOpenMaster)));
+        writer.start_master(0x1A45_DFA3).unwrap();
+        assert!(matches!(writer.finish(), Err(EbmlError::UnclosedMaster(1))));
+    }
+}
@@ -1,32 +1,77 @@
-use std::io::{Read, Seek};
+use std::borrow::Cow;
+use std::fmt;
 
 use thiserror::Error;
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncSeek};
 
+#[cfg(feature = "async")]
+use crate::ebml::async_reader::{AsyncEbmlReader, PrefetchedSource};
 use crate::ebml::{
-    self, EbmlReader, EbmlSchema,
-    error::EbmlError,
-    primitives::{ValueError, parse_string, parse_u64},
-    reader::{ByteRange, ParsedElement},
+    self, DamagedRange, EbmlReader, EbmlSchema,
+    element::Element,
+    error::{EbmlError, ElementPath},
+    primitives::{
+        EbmlDate, ValueError, encode_binary, encode_date, encode_f64, encode_i64, encode_string,
+        encode_u64, encode_utf8, parse_binary, parse_date, parse_f64, parse_float_literal,
+        parse_i64, parse_string, parse_u64, parse_utf8,
+    },
+    reader::{ByteRange, ParsedElement, ReaderLimits},
+    schema::{EBML_ELEMENTS, ElementDef, ElementType},
+    source::ByteSource,
 };
+use crate::matroska::diagnostics::IgnoreDiagnostics;
 
+pub mod diagnostics;
+#[rustfmt::skip]
+pub mod elements;
 mod printer;
+pub use diagnostics::{Diagnostic, DiagnosticCode, DiagnosticSink, Severity};
+pub use elements::*;
+pub use mkvedit_derive::MatroskaElement;
 pub use printer::print_matroska_tree;
 
 pub const EBML_HEADER_ID: u64 = 0x1A45_DFA3;
+pub const EBML_HEADER_VERSION_ID: u64 = 0x4286;
+pub const EBML_HEADER_READ_VERSION_ID: u64 = 0x42F7;
 pub const EBML_HEADER_DOCTYPE_ID: u64 = 0x4282;
 pub const EBML_HEADER_DOCTYPE_VERSION_ID: u64 = 0x4287;
 pub const EBML_HEADER_DOCTYPE_READ_VERSION_ID: u64 = 0x4285;
+pub const EBML_HEADER_DOCTYPE_EXTENSION_ID: u64 = 0x4281;
+pub const EBML_HEADER_DOCTYPE_EXTENSION_NAME_ID: u64 = 0x4283;
+pub const EBML_HEADER_DOCTYPE_EXTENSION_VERSION_ID: u64 = 0x4284;
 pub const EBML_HEADER_MAX_ID_LENGTH_ID: u64 = 0x42F2;
 pub const EBML_HEADER_MAX_SIZE_LENGTH_ID: u64 = 0x42F3;
 
-pub const SEGMENT_ID: u64 = 0x1853_8067;
-pub const INFO_ID: u64 = 0x1549_A966;
+// Highest EBMLReadVersion this parser can read, the version defined by RFC 8794
+const SUPPORTED_EBML_READ_VERSION: u64 = 1;
 
 pub struct MatroskaSchema;
 
+// Returns the definition of `id` from the Matroska or EBML header schema
+fn element_def(id: u64) -> Option<&'static ElementDef> {
+    matroska_element(id).or_else(|| EBML_ELEMENTS.iter().find(|element| element.id == id))
+}
+
+// Returns the schema name of `id`, e.g. for `MatroskaParseError::MissingElement`
+pub(crate) fn element_name(id: u64) -> &'static str {
+    match element_def(id).map(|element| &element.name) {
+        Some(Cow::Borrowed(name)) => name,
+        _ => "unknown element",
+    }
+}
+
 impl EbmlSchema for MatroskaSchema {
-    fn is_master(id: u64) -> bool {
-        matches!(id, EBML_HEADER_ID | SEGMENT_ID)
+    fn is_master(&self, id: u64) -> bool {
+        element_def(id).is_some_and(|element| element.element_type == ElementType::Master)
+    }
+
+    fn definition(&self, id: u64) -> Option<&ElementDef> {
+        element_def(id)
+    }
+
+    fn definitions(&self) -> Box<dyn Iterator<Item = &ElementDef> + '_> {
+        Box::new(MATROSKA_ELEMENTS.iter().chain(EBML_ELEMENTS))
     }
 }
 
@@ -41,6 +86,30 @@ pub enum MatroskaParseError {
     #[error("missing required element: {0}")]
     MissingElement(&'static str),
 
+    #[error("unsupported docType: {0:?}")]
+    UnsupportedDocType(String),
+
+    #[error(
+        "docType {doctype} requires read version {read_version}, only {supported} is supported"
+    )]
+    UnsupportedReadVersion {
+        doctype: DocType,
+        read_version: u64,
+        supported: u64,
+    },
+
+    // Any other error, with the offset and path of the element where it occurred
+    #[error("{source} at offset {offset} in {path}")]
+    At {
+        offset: u64,
+        path: ElementPath,
+        source: Box<MatroskaParseError>,
+    },
+
+    // A diagnostic promoted to an error by the diagnostic sink
+    #[error("{0}")]
+    Diagnostic(Diagnostic),
+
     #[error("value error: {0}")]
     ValueError(#[from] ValueError),
 
@@ -48,84 +117,279 @@ pub enum MatroskaParseError {
     EbmlError(#[from] EbmlError),
 }
 
+impl MatroskaParseError {
+    // Returns the error without its location
+    pub fn kind(&self) -> &MatroskaParseError {
+        match self {
+            MatroskaParseError::At { source, .. } => source.kind(),
+            error => error,
+        }
+    }
+
+    // Returns the absolute byte offset of the error, if known
+    pub fn offset(&self) -> Option<u64> {
+        match self {
+            MatroskaParseError::At { offset, .. } => Some(*offset),
+            MatroskaParseError::Diagnostic(diagnostic) => Some(diagnostic.offset),
+            MatroskaParseError::EbmlError(error) => error.offset(),
+            _ => None,
+        }
+    }
+
+    // Returns the path of the element where the error occurred, if known
+    pub fn path(&self) -> Option<&ElementPath> {
+        match self {
+            MatroskaParseError::At { path, .. } => Some(path),
+            MatroskaParseError::Diagnostic(diagnostic) => Some(&diagnostic.path),
+            MatroskaParseError::EbmlError(error) => error.path(),
+            _ => None,
+        }
+    }
+}
+
 pub trait MatroskaElement {
     const ID: u64;
-    fn parse<R: Read + Seek>(
-        reader: &mut MatroskaReader<R>,
+    fn parse<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
         raw: &ParsedElement,
     ) -> Result<Self, MatroskaParseError>
     where
         Self: Sized;
 }
 
-pub struct MatroskaReader<R: Read + Seek> {
+pub struct MatroskaReader<'s, R: ByteSource> {
     ebml_reader: EbmlReader<R>,
+    // IDs of the elements currently being parsed, for error locations
+    path: Vec<u64>,
+    diagnostics: Box<dyn DiagnosticSink + 's>,
 }
 
-impl<R: Read + Seek> MatroskaReader<R> {
+impl<R: ByteSource> MatroskaReader<'static, R> {
+    // Creates a reader which ignores diagnostics
     pub fn new(reader: R) -> Self {
+        Self::with_limits(reader, ReaderLimits::default())
+    }
+
+    pub fn with_limits(reader: R, limits: ReaderLimits) -> Self {
+        Self::with_sink(reader, limits, IgnoreDiagnostics)
+    }
+}
+
+impl<'s, R: ByteSource> MatroskaReader<'s, R> {
+    // Creates a reader reporting diagnostics to `sink`, e.g. a `&mut Vec<Diagnostic>`
+    pub fn with_sink(reader: R, limits: ReaderLimits, sink: impl DiagnosticSink + 's) -> Self {
         Self {
-            ebml_reader: EbmlReader::new(reader),
+            ebml_reader: EbmlReader::with_limits(reader, limits),
+            path: Vec::new(),
+            diagnostics: Box::new(sink),
+        }
+    }
+
+    // Reports a diagnostic about `raw`, a child of the element being parsed
+    // Fails if the sink promotes the diagnostic to an error
+    pub fn report(
+        &mut self,
+        severity: Severity,
+        code: DiagnosticCode,
+        raw: &ParsedElement,
+        message: String,
+    ) -> Result<(), MatroskaParseError> {
+        let mut ids = self.path.clone();
+        ids.push(raw.id);
+        let diagnostic = Diagnostic {
+            severity,
+            code,
+            message,
+            offset: raw.header.start,
+            path: ElementPath::new(&ids, &MatroskaSchema),
+        };
+        self.diagnostics
+            .report(diagnostic)
+            .map_err(MatroskaParseError::Diagnostic)
+    }
+
+    // Reports an unhandled child `raw` of the element being parsed as a warning
+    pub(crate) fn report_unhandled(
+        &mut self,
+        raw: &ParsedElement,
+    ) -> Result<(), MatroskaParseError> {
+        let name = MatroskaSchema.name(raw.id).unwrap_or("unknown element");
+        self.report(
+            Severity::Warning,
+            DiagnosticCode::UnhandledElement,
+            raw,
+            format!("unhandled element {name} ({:#X})", raw.id),
+        )
+    }
+
+    // Parses `raw` as `T`, adding the location of `raw` to errors
+    // which do not already come with a more specific one
+    pub fn parse_element<T: MatroskaElement>(
+        &mut self,
+        raw: &ParsedElement,
+    ) -> Result<T, MatroskaParseError> {
+        self.path.push(raw.id);
+        let result = T::parse(self, raw).map_err(|error| self.locate(raw, error));
+        self.path.pop();
+        result
+    }
+
+    // Adds the location of `raw`, the last element of the current path, to `error`
+    fn locate(&self, raw: &ParsedElement, error: MatroskaParseError) -> MatroskaParseError {
+        match error {
+            MatroskaParseError::At { .. } | MatroskaParseError::Diagnostic(_) => error,
+            MatroskaParseError::EbmlError(error) if error.offset().is_some() => {
+                MatroskaParseError::EbmlError(error)
+            }
+            error => MatroskaParseError::At {
+                offset: raw.header.start,
+                path: ElementPath::new(&self.path, &MatroskaSchema),
+                source: Box::new(error),
+            },
         }
     }
 
     fn read_range(&mut self, range: &ByteRange) -> Result<Vec<u8>, MatroskaParseError> {
         self.ebml_reader
             .read_range(range)
+            .map(Cow::into_owned)
             .map_err(MatroskaParseError::from)
     }
 }
 
+// Where the value of a field comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueSource {
+    // Stored in the element, including empty elements without a schema default
+    Explicit,
+    // The element is empty and takes the schema default
+    EmptyDefault,
+    // The element is not present and takes the schema default
+    Absent,
+}
+
 #[derive(Debug)]
 pub struct Field<T> {
     pub raw: ParsedElement,
     pub value: T,
+    // `Explicit` or `EmptyDefault`, as the element is present
+    pub source: ValueSource,
 }
 
-impl<T> Field<T> {
-    fn parse<R: Read + Seek>(
-        reader: &mut MatroskaReader<R>,
+impl<T: FieldValue> Field<T> {
+    // Parses `raw` with `parse_func`, or takes the schema default if empty
+    // Empty elements without a (valid) schema default take the empty value of their type,
+    // e.g. 0 or an empty string, as parsed by `parse_func`
+    fn parse<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
         raw: &ParsedElement,
         parse_func: impl Fn(Vec<u8>) -> Result<T, ValueError>,
     ) -> Result<Self, MatroskaParseError> {
-        //TODO: Handle raw.data.lenth == 0 (default value)
-        let bytes = reader.read_range(&raw.data)?;
+        let default = (raw.data.length == 0)
+            .then(|| schema_default(raw.id))
+            .flatten();
+        if let Some(value) = default {
+            return Ok(Self {
+                raw: raw.clone(),
+                value,
+                source: ValueSource::EmptyDefault,
+            });
+        }
+
+        reader.path.push(raw.id);
+        let value = reader
+            .read_range(&raw.data)
+            .and_then(|bytes| parse_func(bytes).map_err(MatroskaParseError::from))
+            .map_err(|error| reader.locate(raw, error));
+        reader.path.pop();
         Ok(Self {
             raw: raw.clone(),
-            value: parse_func(bytes)?,
+            value: value?,
+            source: ValueSource::Explicit,
         })
     }
 }
 
 impl Field<String> {
-    pub fn parse_string<R: Read + Seek>(
-        reader: &mut MatroskaReader<R>,
+    pub fn parse_string<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
         raw: &ParsedElement,
     ) -> Result<Self, MatroskaParseError> {
         Self::parse(reader, raw, parse_string)
     }
+
+    pub fn parse_utf8<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
+        raw: &ParsedElement,
+    ) -> Result<Self, MatroskaParseError> {
+        Self::parse(reader, raw, parse_utf8)
+    }
 }
 
 impl Field<u64> {
-    pub fn parse_u64<R: Read + Seek>(
-        reader: &mut MatroskaReader<R>,
+    pub fn parse_u64<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
         raw: &ParsedElement,
     ) -> Result<Self, MatroskaParseError> {
         Self::parse(reader, raw, parse_u64)
     }
 }
 
+impl Field<i64> {
+    pub fn parse_i64<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
+        raw: &ParsedElement,
+    ) -> Result<Self, MatroskaParseError> {
+        Self::parse(reader, raw, parse_i64)
+    }
+}
+
+impl Field<f64> {
+    pub fn parse_f64<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
+        raw: &ParsedElement,
+    ) -> Result<Self, MatroskaParseError> {
+        Self::parse(reader, raw, parse_f64)
+    }
+}
+
+impl Field<EbmlDate> {
+    pub fn parse_date<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
+        raw: &ParsedElement,
+    ) -> Result<Self, MatroskaParseError> {
+        Self::parse(reader, raw, parse_date)
+    }
+}
+
+impl Field<Vec<u8>> {
+    pub fn parse_binary<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
+        raw: &ParsedElement,
+    ) -> Result<Self, MatroskaParseError> {
+        Self::parse(reader, raw, parse_binary)
+    }
+}
+
 #[derive(Debug)]
 pub enum OptionalField<T> {
     Present(Field<T>),
     Default(T),
 }
 
-impl<T: Copy> OptionalField<T> {
-    pub fn value(&self) -> T {
+impl<T> OptionalField<T> {
+    pub fn source(&self) -> ValueSource {
         match self {
-            OptionalField::Present(field) => field.value,
-            OptionalField::Default(value) => *value,
+            OptionalField::Present(field) => field.source,
+            OptionalField::Default(_) => ValueSource::Absent,
+        }
+    }
+
+    // Returns the value without copying it, e.g. for strings
+    pub fn value_ref(&self) -> &T {
+        match self {
+            OptionalField::Present(field) => &field.value,
+            OptionalField::Default(value) => value,
         }
     }
 
@@ -141,12 +405,205 @@ impl<T: Copy> OptionalField<T> {
     }
 }
 
+impl<T: FieldValue> OptionalField<T> {
+    // Takes the schema default of `id` if `field` is absent,
+    // failing with `MissingElement` if the schema has no default for it
+    pub fn new_or_schema_default(
+        field: Option<Field<T>>,
+        id: u64,
+    ) -> Result<Self, MatroskaParseError> {
+        match field {
+            Some(f) => Ok(OptionalField::Present(f)),
+            None => schema_default(id)
+                .map(OptionalField::Default)
+                .ok_or(MatroskaParseError::MissingElement(element_name(id))),
+        }
+    }
+}
+
+impl<T: Copy> OptionalField<T> {
+    pub fn value(&self) -> T {
+        match self {
+            OptionalField::Present(field) => field.value,
+            OptionalField::Default(value) => *value,
+        }
+    }
+}
+
+// Types of the values of `Field`s generated by `#[derive(MatroskaElement)]`
+pub trait FieldValue: Sized {
+    fn parse_field<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
+        raw: &ParsedElement,
+    ) -> Result<Field<Self>, MatroskaParseError>;
+
+    // Encodes the value as the data of the element `id`
+    fn encode(&self, id: u64) -> Result<Vec<u8>, ValueError>;
+
+    // Parses a default value in the textual form used by the schema
+    fn parse_default(default: &str) -> Option<Self>;
+}
+
+// Returns the schema default of `id`, the value of empty and absent elements,
+// `None` if the schema has no (valid) default for it
+pub(crate) fn schema_default<T: FieldValue>(id: u64) -> Option<T> {
+    element_def(id)?
+        .default
+        .as_deref()
+        .and_then(T::parse_default)
+}
+
+// String and UTF-8 elements are told apart by their schema type
+impl FieldValue for String {
+    fn parse_field<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
+        raw: &ParsedElement,
+    ) -> Result<Field<Self>, MatroskaParseError> {
+        if is_utf8(raw.id) {
+            Field::parse_utf8(reader, raw)
+        } else {
+            Field::parse_string(reader, raw)
+        }
+    }
+
+    fn encode(&self, id: u64) -> Result<Vec<u8>, ValueError> {
+        if is_utf8(id) {
+            Ok(encode_utf8(self))
+        } else {
+            encode_string(self)
+        }
+    }
+
+    fn parse_default(default: &str) -> Option<Self> {
+        Some(default.to_string())
+    }
+}
+
+fn is_utf8(id: u64) -> bool {
+    element_def(id).is_some_and(|element| element.element_type == ElementType::Utf8)
+}
+
+impl FieldValue for u64 {
+    fn parse_field<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
+        raw: &ParsedElement,
+    ) -> Result<Field<Self>, MatroskaParseError> {
+        Field::parse_u64(reader, raw)
+    }
+
+    fn encode(&self, _: u64) -> Result<Vec<u8>, ValueError> {
+        Ok(encode_u64(*self))
+    }
+
+    fn parse_default(default: &str) -> Option<Self> {
+        default.parse().ok()
+    }
+}
+
+impl FieldValue for i64 {
+    fn parse_field<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
+        raw: &ParsedElement,
+    ) -> Result<Field<Self>, MatroskaParseError> {
+        Field::parse_i64(reader, raw)
+    }
+
+    fn encode(&self, _: u64) -> Result<Vec<u8>, ValueError> {
+        Ok(encode_i64(*self))
+    }
+
+    fn parse_default(default: &str) -> Option<Self> {
+        default.parse().ok()
+    }
+}
+
+impl FieldValue for f64 {
+    fn parse_field<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
+        raw: &ParsedElement,
+    ) -> Result<Field<Self>, MatroskaParseError> {
+        Field::parse_f64(reader, raw)
+    }
+
+    fn encode(&self, _: u64) -> Result<Vec<u8>, ValueError> {
+        Ok(encode_f64(*self))
+    }
+
+    fn parse_default(default: &str) -> Option<Self> {
+        parse_float_literal(default)
+    }
+}
+
+impl FieldValue for EbmlDate {
+    fn parse_field<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
+        raw: &ParsedElement,
+    ) -> Result<Field<Self>, MatroskaParseError> {
+        Field::parse_date(reader, raw)
+    }
+
+    fn encode(&self, _: u64) -> Result<Vec<u8>, ValueError> {
+        Ok(encode_date(*self))
+    }
+
+    // Date defaults are nanoseconds since the EBML epoch
+    fn parse_default(default: &str) -> Option<Self> {
+        default.parse().ok().map(EbmlDate::new)
+    }
+}
+
+impl FieldValue for Vec<u8> {
+    fn parse_field<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
+        raw: &ParsedElement,
+    ) -> Result<Field<Self>, MatroskaParseError> {
+        Field::parse_binary(reader, raw)
+    }
+
+    fn encode(&self, _: u64) -> Result<Vec<u8>, ValueError> {
+        Ok(encode_binary(self))
+    }
+
+    // Binary defaults are hexadecimal without a prefix
+    fn parse_default(default: &str) -> Option<Self> {
+        (0..default.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(default.get(i..i + 2)?, 16).ok())
+            .collect()
+    }
+}
+
+// Serializes a typed element, generated by `#[derive(MatroskaElement)]`
+// Only the modeled children are written, unhandled children of the parsed element are dropped
+pub trait ToElement {
+    fn to_element(&self) -> Result<Element, ValueError>;
+}
+
+// Empty elements taking the schema default are written empty again
+impl<T: FieldValue> ToElement for Field<T> {
+    fn to_element(&self) -> Result<Element, ValueError> {
+        let data = match self.source {
+            ValueSource::EmptyDefault => Vec::new(),
+            ValueSource::Explicit | ValueSource::Absent => self.value.encode(self.raw.id)?,
+        };
+        Ok(Element::Raw {
+            id: self.raw.id,
+            data,
+        })
+    }
+}
+
+// EBML header of any document type, only checked against the constraints of RFC 8794
+// Document type specific constraints are checked by the document, see `DocType::check`
 #[derive(Debug)]
 pub struct EbmlHeader {
     pub raw: ParsedElement,
+    pub ebml_version: OptionalField<u64>,
+    pub ebml_read_version: OptionalField<u64>,
     pub doctype: Field<String>,
     pub doctype_version: OptionalField<u64>,
     pub doctype_read_version: OptionalField<u64>,
+    pub doctype_extensions: Vec<DocTypeExtension>,
     pub max_id_length: OptionalField<u64>,
     pub max_size_length: OptionalField<u64>,
 }
@@ -154,20 +611,29 @@ pub struct EbmlHeader {
 impl MatroskaElement for EbmlHeader {
     const ID: u64 = EBML_HEADER_ID;
 
-    fn parse<R: Read + Seek>(
-        reader: &mut MatroskaReader<R>,
+    fn parse<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
         raw: &ParsedElement,
     ) -> Result<Self, MatroskaParseError> {
         assert!(raw.id == Self::ID, "trying to parse invalid element");
 
+        let mut ebml_version = None;
+        let mut ebml_read_version = None;
         let mut doctype = None;
         let mut doctype_version = None;
         let mut doctype_read_version = None;
+        let mut doctype_extensions = Vec::new();
         let mut max_id_length = None;
         let mut max_size_length = None;
 
         for child in raw.children.as_deref().unwrap_or(&[]) {
             match child.id {
+                EBML_HEADER_VERSION_ID => {
+                    ebml_version = Some(Field::parse_u64(reader, child)?);
+                }
+                EBML_HEADER_READ_VERSION_ID => {
+                    ebml_read_version = Some(Field::parse_u64(reader, child)?);
+                }
                 EBML_HEADER_DOCTYPE_ID => {
                     doctype = Some(Field::parse_string(reader, child)?);
                 }
@@ -177,73 +643,171 @@ impl MatroskaElement for EbmlHeader {
                 EBML_HEADER_DOCTYPE_READ_VERSION_ID => {
                     doctype_read_version = Some(Field::parse_u64(reader, child)?);
                 }
+                EBML_HEADER_DOCTYPE_EXTENSION_ID => {
+                    doctype_extensions.push(reader.parse_element(child)?);
+                }
                 EBML_HEADER_MAX_ID_LENGTH_ID => {
                     max_id_length = Some(Field::parse_u64(reader, child)?);
                 }
                 EBML_HEADER_MAX_SIZE_LENGTH_ID => {
                     max_size_length = Some(Field::parse_u64(reader, child)?);
                 }
-                _ => println!("Warning: unhandled EBML Header child ID {:X}", child.id),
+                _ => reader.report_unhandled(child)?,
             }
         }
 
+        // Absent elements take their default from `EBML_ELEMENTS`
+        let ebml_version =
+            OptionalField::new_or_schema_default(ebml_version, EBML_HEADER_VERSION_ID)?;
+        let ebml_read_version =
+            OptionalField::new_or_schema_default(ebml_read_version, EBML_HEADER_READ_VERSION_ID)?;
         let doctype = doctype.ok_or(MatroskaParseError::InvalidEbmlHeader("missing docType"))?;
-        let doctype_version = OptionalField::new_or_default(doctype_version, 1);
-        let doctype_read_version = OptionalField::new_or_default(doctype_read_version, 1);
-        let max_id_length = OptionalField::new_or_default(max_id_length, 4);
-        let max_size_length = OptionalField::new_or_default(max_size_length, 8);
+        let doctype_version =
+            OptionalField::new_or_schema_default(doctype_version, EBML_HEADER_DOCTYPE_VERSION_ID)?;
+        let doctype_read_version = OptionalField::new_or_schema_default(
+            doctype_read_version,
+            EBML_HEADER_DOCTYPE_READ_VERSION_ID,
+        )?;
+        let max_id_length =
+            OptionalField::new_or_schema_default(max_id_length, EBML_HEADER_MAX_ID_LENGTH_ID)?;
+        let max_size_length =
+            OptionalField::new_or_schema_default(max_size_length, EBML_HEADER_MAX_SIZE_LENGTH_ID)?;
 
-        // Validate Matroska EBML constraints
-        if doctype.value != "matroska" {
+        // Validate the constraints of RFC 8794, which apply to all document types
+        if ebml_read_version.value() > SUPPORTED_EBML_READ_VERSION {
             return Err(MatroskaParseError::InvalidEbmlHeader(
-                "docType is not matroska",
+                "unsupported EBMLReadVersion",
             ));
         }
-        if max_id_length.value() != 4 {
+        if max_id_length.value() < 4 {
             return Err(MatroskaParseError::InvalidEbmlHeader(
-                "maxIDLength is not 4",
+                "maxIDLength is less than 4",
             ));
         }
-        if max_size_length.value() != 8 {
+        if !(1..=8).contains(&max_size_length.value()) {
             return Err(MatroskaParseError::InvalidEbmlHeader(
-                "maxSizeLength is not 8",
+                "maxSizeLength is not between 1 and 8",
             ));
         }
 
         Ok(Self {
             raw: raw.clone(),
+            ebml_version,
+            ebml_read_version,
             doctype,
             doctype_version,
             doctype_read_version,
+            doctype_extensions,
             max_id_length,
             max_size_length,
         })
     }
 }
 
+// Extension of the document type used in the document, e.g. by a muxer adding elements
+#[derive(Debug, MatroskaElement)]
+#[matroska(id = EBML_HEADER_DOCTYPE_EXTENSION_ID)]
+pub struct DocTypeExtension {
+    pub raw: ParsedElement,
+    #[matroska(id = EBML_HEADER_DOCTYPE_EXTENSION_NAME_ID)]
+    pub name: Field<String>,
+    #[matroska(id = EBML_HEADER_DOCTYPE_EXTENSION_VERSION_ID)]
+    pub version: Field<u64>,
+}
+
+// Document types `MatroskaDocument` can parse
+// WebM is a subset of Matroska, parsed with the same schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocType {
+    Matroska,
+    WebM,
+}
+
+impl DocType {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "matroska" => Some(Self::Matroska),
+            "webm" => Some(Self::WebM),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Matroska => "matroska",
+            Self::WebM => "webm",
+        }
+    }
+
+    // Highest DocTypeReadVersion this parser can read,
+    // the version of the schema `MatroskaSchema` is generated from
+    pub fn supported_read_version(self) -> u64 {
+        match self {
+            Self::Matroska | Self::WebM => 4,
+        }
+    }
+
+    // Checks `header` against the constraints of its document type,
+    // returning the document type if it is supported
+    pub fn check(header: &EbmlHeader) -> Result<Self, MatroskaParseError> {
+        let doctype = Self::from_name(&header.doctype.value)
+            .ok_or_else(|| MatroskaParseError::UnsupportedDocType(header.doctype.value.clone()))?;
+
+        if header.doctype_read_version.value() > doctype.supported_read_version() {
+            return Err(MatroskaParseError::UnsupportedReadVersion {
+                doctype,
+                read_version: header.doctype_read_version.value(),
+                supported: doctype.supported_read_version(),
+            });
+        }
+        if header.max_id_length.value() != 4 {
+            return Err(MatroskaParseError::InvalidEbmlHeader(
+                "maxIDLength is not 4",
+            ));
+        }
+        if header.max_size_length.value() != 8 {
+            return Err(MatroskaParseError::InvalidEbmlHeader(
+                "maxSizeLength is not 8",
+            ));
+        }
+        Ok(doctype)
+    }
+}
+
+impl fmt::Display for DocType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
 #[derive(Debug)]
 pub struct Segment {
     pub raw: ParsedElement,
     pub info: Info,
+    pub tracks: Option<Tracks>,
 }
 
 impl MatroskaElement for Segment {
     const ID: u64 = SEGMENT_ID;
 
-    fn parse<R: Read + Seek>(
-        reader: &mut MatroskaReader<R>,
+    fn parse<R: ByteSource>(
+        reader: &mut MatroskaReader<'_, R>,
         raw: &ParsedElement,
     ) -> Result<Self, MatroskaParseError> {
         assert!(raw.id == Self::ID, "trying to parse invalid element");
 
         let mut info = None;
+        let mut tracks = None;
 
         for child in raw.children.as_deref().unwrap_or(&[]) {
             match child.id {
                 INFO_ID => {
-                    info = Some(Info::parse(reader, child)?);
+                    info = Some(reader.parse_element(child)?);
+                }
+                TRACKS_ID => {
+                    tracks = Some(reader.parse_element(child)?);
                 }
-                _ => println!("Warning: unhandled Segment child ID {:X}", child.id),
+                _ => reader.report_unhandled(child)?,
             }
         }
 
@@ -252,40 +816,219 @@ impl MatroskaElement for Segment {
         Ok(Self {
             raw: raw.clone(),
             info,
+            tracks,
         })
     }
 }
 
-#[derive(Debug)]
+// General information about the Segment
+// MuxingApp and WritingApp are mandatory in the specification, but are optional here
+// as some muxers omit them
+#[derive(Debug, MatroskaElement)]
+#[matroska(id = INFO_ID)]
 pub struct Info {
     pub raw: ParsedElement,
+    #[matroska(id = SEGMENT_UUID_ID)]
+    pub segment_uuid: Option<Field<Vec<u8>>>,
+    #[matroska(id = SEGMENT_FILENAME_ID)]
+    pub segment_filename: Option<Field<String>>,
+    #[matroska(id = PREV_UUID_ID)]
+    pub prev_uuid: Option<Field<Vec<u8>>>,
+    #[matroska(id = PREV_FILENAME_ID)]
+    pub prev_filename: Option<Field<String>>,
+    #[matroska(id = NEXT_UUID_ID)]
+    pub next_uuid: Option<Field<Vec<u8>>>,
+    #[matroska(id = NEXT_FILENAME_ID)]
+    pub next_filename: Option<Field<String>>,
+    #[matroska(id = SEGMENT_FAMILY_ID)]
+    pub segment_families: Vec<Field<Vec<u8>>>,
+    #[matroska(id = CHAPTER_TRANSLATE_ID)]
+    pub chapter_translates: Vec<ChapterTranslate>,
+    // Nanoseconds per Segment tick, the unit of Duration and Cluster timestamps
+    #[matroska(id = TIMESTAMP_SCALE_ID)]
+    pub timestamp_scale: OptionalField<u64>,
+    // Duration in Segment ticks
+    #[matroska(id = DURATION_ID)]
+    pub duration: Option<Field<f64>>,
+    #[matroska(id = DATE_UTC_ID)]
+    pub date_utc: Option<Field<EbmlDate>>,
+    #[matroska(id = TITLE_ID)]
+    pub title: Option<Field<String>>,
+    #[matroska(id = MUXING_APP_ID)]
+    pub muxing_app: Option<Field<String>>,
+    #[matroska(id = WRITING_APP_ID)]
+    pub writing_app: Option<Field<String>>,
 }
 
-impl MatroskaElement for Info {
-    const ID: u64 = INFO_ID;
+// Mapping of a chapter codec's identifier for the Segment to edition UIDs
+#[derive(Debug, MatroskaElement)]
+#[matroska(id = CHAPTER_TRANSLATE_ID)]
+pub struct ChapterTranslate {
+    pub raw: ParsedElement,
+    #[matroska(id = CHAPTER_TRANSLATE_ID_ID)]
+    pub id: Field<Vec<u8>>,
+    #[matroska(id = CHAPTER_TRANSLATE_CODEC_ID)]
+    pub codec: Field<u64>,
+    #[matroska(id = CHAPTER_TRANSLATE_EDITION_UID_ID)]
+    pub edition_uids: Vec<Field<u64>>,
+}
 
-    fn parse<R: Read + Seek>(
-        _: &mut MatroskaReader<R>,
-        raw: &ParsedElement,
-    ) -> Result<Self, MatroskaParseError> {
-        assert!(raw.id == Self::ID, "trying to parse invalid element");
+#[derive(Debug, MatroskaElement)]
+#[matroska(id = TRACKS_ID)]
+pub struct Tracks {
+    pub raw: ParsedElement,
+    #[matroska(id = TRACK_ENTRY_ID)]
+    pub entries: Vec<TrackEntry>,
+}
 
-        //TODO: Parse Info children
-        Ok(Self { raw: raw.clone() })
-    }
+// Track values are kept as in the schema, e.g. flags are 0 or 1
+#[derive(Debug, MatroskaElement)]
+#[matroska(id = TRACK_ENTRY_ID)]
+pub struct TrackEntry {
+    pub raw: ParsedElement,
+    #[matroska(id = TRACK_NUMBER_ID)]
+    pub track_number: Field<u64>,
+    #[matroska(id = TRACK_UID_ID)]
+    pub track_uid: Field<u64>,
+    // 1 for video, 2 for audio, 17 for subtitles, see the specification for other types
+    #[matroska(id = TRACK_TYPE_ID)]
+    pub track_type: Field<u64>,
+    #[matroska(id = FLAG_ENABLED_ID)]
+    pub flag_enabled: OptionalField<u64>,
+    #[matroska(id = FLAG_DEFAULT_ID)]
+    pub flag_default: OptionalField<u64>,
+    #[matroska(id = FLAG_FORCED_ID)]
+    pub flag_forced: OptionalField<u64>,
+    #[matroska(id = FLAG_HEARING_IMPAIRED_ID)]
+    pub flag_hearing_impaired: Option<Field<u64>>,
+    #[matroska(id = FLAG_VISUAL_IMPAIRED_ID)]
+    pub flag_visual_impaired: Option<Field<u64>>,
+    #[matroska(id = FLAG_TEXT_DESCRIPTIONS_ID)]
+    pub flag_text_descriptions: Option<Field<u64>>,
+    #[matroska(id = FLAG_ORIGINAL_ID)]
+    pub flag_original: Option<Field<u64>>,
+    #[matroska(id = FLAG_COMMENTARY_ID)]
+    pub flag_commentary: Option<Field<u64>>,
+    #[matroska(id = FLAG_LACING_ID)]
+    pub flag_lacing: OptionalField<u64>,
+    // Nanoseconds per frame
+    #[matroska(id = DEFAULT_DURATION_ID)]
+    pub default_duration: Option<Field<u64>>,
+    #[matroska(id = NAME_ID)]
+    pub name: Option<Field<String>>,
+    // ISO 639-2 language code, superseded by LanguageBCP47 if present
+    #[matroska(id = LANGUAGE_ID)]
+    pub language: OptionalField<String>,
+    #[matroska(id = LANGUAGE_BCP47_ID)]
+    pub language_bcp47: Option<Field<String>>,
+    #[matroska(id = CODEC_ID_ID)]
+    pub codec_id: Field<String>,
+    #[matroska(id = CODEC_PRIVATE_ID)]
+    pub codec_private: Option<Field<Vec<u8>>>,
+    // Nanoseconds of codec delay to skip at the start of the track
+    #[matroska(id = CODEC_DELAY_ID)]
+    pub codec_delay: OptionalField<u64>,
+    // Nanoseconds to decode before a seek target
+    #[matroska(id = SEEK_PRE_ROLL_ID)]
+    pub seek_pre_roll: OptionalField<u64>,
+    #[matroska(id = MAX_BLOCK_ADDITION_ID_ID)]
+    pub max_block_addition_id: OptionalField<u64>,
 }
 
 #[derive(Debug)]
 pub struct MatroskaDocument {
     pub ebml_header: EbmlHeader,
+    // Document type of the EBML header, whose constraints the document satisfies
+    pub doctype: DocType,
     pub segment: Segment,
 }
 
 impl MatroskaDocument {
-    pub fn parse_from<R: Read + Seek>(reader: R) -> Result<Self, MatroskaParseError> {
-        let mut matroska_reader = MatroskaReader::new(reader);
-        let root = ebml::read_root::<MatroskaSchema, _>(&mut matroska_reader.ebml_reader)?;
+    pub fn parse_from<R: ByteSource>(reader: R) -> Result<Self, MatroskaParseError> {
+        Self::parse_with_limits(reader, ReaderLimits::default())
+    }
+
+    // Same as `parse_from`, with custom limits e.g. for untrusted input
+    pub fn parse_with_limits<R: ByteSource>(
+        reader: R,
+        limits: ReaderLimits,
+    ) -> Result<Self, MatroskaParseError> {
+        Self::parse_with(reader, limits, IgnoreDiagnostics)
+    }
+
+    // Same as `parse_with_limits`, reporting diagnostics such as unhandled elements to `sink`
+    pub fn parse_with<R: ByteSource>(
+        reader: R,
+        limits: ReaderLimits,
+        sink: impl DiagnosticSink,
+    ) -> Result<Self, MatroskaParseError> {
+        let mut matroska_reader = MatroskaReader::with_sink(reader, limits, sink);
+        let root = ebml::read_root(&mut matroska_reader.ebml_reader, &MatroskaSchema)?;
+        Self::parse_root(&mut matroska_reader, &root)
+    }
 
+    // Async counterpart of `parse_from`, for `tokio` inputs
+    // The element tree and the data of the EBML header, Info and Tracks are read
+    // asynchronously, the document is then parsed from memory
+    #[cfg(feature = "async")]
+    pub async fn parse_from_async<R: AsyncRead + AsyncSeek + Unpin>(
+        reader: R,
+    ) -> Result<Self, MatroskaParseError> {
+        Self::parse_from_async_with(reader, ReaderLimits::default(), IgnoreDiagnostics).await
+    }
+
+    // Same as `parse_from_async`, with custom limits and reporting diagnostics to `sink`
+    #[cfg(feature = "async")]
+    pub async fn parse_from_async_with<R: AsyncRead + AsyncSeek + Unpin>(
+        reader: R,
+        limits: ReaderLimits,
+        sink: impl DiagnosticSink,
+    ) -> Result<Self, MatroskaParseError> {
+        let mut ebml_reader = AsyncEbmlReader::with_limits(reader, limits);
+        let root = ebml::read_root_async(&mut ebml_reader, &MatroskaSchema).await?;
+        // Only the data `parse_root` reads is prefetched, as elements such as Clusters
+        // and Attachments make up most of the file and may exceed the payload limit
+        let segment_children = root
+            .get(1)
+            .and_then(|segment| segment.children.as_deref())
+            .unwrap_or(&[])
+            .iter()
+            .filter(|child| child.id == INFO_ID || child.id == TRACKS_ID);
+        let source = PrefetchedSource::prefetch(
+            &mut ebml_reader,
+            root.first().into_iter().chain(segment_children),
+        )
+        .await?;
+        let mut matroska_reader = MatroskaReader::with_sink(source, limits, sink);
+        Self::parse_root(&mut matroska_reader, &root)
+    }
+
+    // Same as `parse_from`, but skips damaged data inside the Segment instead of failing,
+    // returning the document parsed from the remaining elements and the skipped byte ranges
+    // The EBML header and the Segment Info must still be readable
+    pub fn parse_lenient<R: ByteSource>(
+        reader: R,
+    ) -> Result<(Self, Vec<DamagedRange>), MatroskaParseError> {
+        Self::parse_lenient_with(reader, ReaderLimits::default(), IgnoreDiagnostics)
+    }
+
+    // Same as `parse_lenient`, with custom limits and reporting diagnostics to `sink`
+    pub fn parse_lenient_with<R: ByteSource>(
+        reader: R,
+        limits: ReaderLimits,
+        sink: impl DiagnosticSink,
+    ) -> Result<(Self, Vec<DamagedRange>), MatroskaParseError> {
+        let mut matroska_reader = MatroskaReader::with_sink(reader, limits, sink);
+        let (root, damaged) =
+            ebml::read_root_lenient(&mut matroska_reader.ebml_reader, &MatroskaSchema)?;
+        let document = Self::parse_root(&mut matroska_reader, &root)?;
+        Ok((document, damaged))
+    }
+
+    fn parse_root<R: ByteSource>(
+        matroska_reader: &mut MatroskaReader<'_, R>,
+        root: &[ParsedElement],
+    ) -> Result<Self, MatroskaParseError> {
         if root.is_empty() {
             return Err(MatroskaParseError::MissingEbmlHeader);
         }
@@ -300,12 +1043,843 @@ impl MatroskaDocument {
             return Err(MatroskaParseError::MissingEbmlHeader);
         }
 
-        let ebml_header = EbmlHeader::parse(&mut matroska_reader, &root[0])?;
-        let segment = Segment::parse(&mut matroska_reader, &root[1])?;
+        let ebml_header: EbmlHeader = matroska_reader.parse_element(&root[0])?;
+        matroska_reader.path.push(EBML_HEADER_ID);
+        let doctype =
+            DocType::check(&ebml_header).map_err(|error| matroska_reader.locate(&root[0], error));
+        matroska_reader.path.pop();
+        let doctype = doctype?;
+        let segment = matroska_reader.parse_element(&root[1])?;
 
         Ok(Self {
             ebml_header,
+            doctype,
             segment,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::tree_printer::{TreePrintable, TreePrinter};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_matroska_schema() {
+        assert!(MatroskaSchema.is_master(EBML_HEADER_ID));
+        assert!(MatroskaSchema.is_master(TRACK_ENTRY_ID));
+        assert!(!MatroskaSchema.is_master(TIMESTAMP_SCALE_ID));
+        assert_eq!(MatroskaSchema.level(SEGMENT_ID), Some(0));
+        assert_eq!(MatroskaSchema.level(CLUSTER_ID), Some(1));
+        assert_eq!(MatroskaSchema.level(PIXEL_WIDTH_ID), Some(4));
+        assert_eq!(MatroskaSchema.level(0xEC), None);
+
+        let timestamp_scale = matroska_element(TIMESTAMP_SCALE_ID).unwrap();
+        assert_eq!(timestamp_scale.name, "TimestampScale");
+        assert_eq!(timestamp_scale.parent_path(), Some("\\Segment\\Info"));
+        assert_eq!(timestamp_scale.default.as_deref(), Some("1000000"));
+        assert!(matroska_element(0xEC).is_none());
+    }
+
+    #[test]
+    fn test_parse_empty_and_absent_fields() {
+        let bytes = Element::Master {
+            id: EBML_HEADER_ID,
+            children: vec![
+                Element::Raw {
+                    id: EBML_HEADER_DOCTYPE_ID,
+                    data: b"matroska".to_vec(),
+                },
+                Element::Raw {
+                    id: EBML_HEADER_MAX_ID_LENGTH_ID,
+                    data: vec![],
+                },
+                Element::Raw {
+                    id: EBML_HEADER_DOCTYPE_READ_VERSION_ID,
+                    data: vec![0x02],
+                },
+            ],
+        }
+        .to_bytes()
+        .unwrap();
+        let mut reader = MatroskaReader::new(Cursor::new(bytes));
+        let root = ebml::read_root(&mut reader.ebml_reader, &MatroskaSchema).unwrap();
+        let header: EbmlHeader = reader.parse_element(&root[0]).unwrap();
+
+        assert_eq!(header.doctype.source, ValueSource::Explicit);
+        assert_eq!(header.max_id_length.value(), 4);
+        assert_eq!(header.max_id_length.source(), ValueSource::EmptyDefault);
+        assert_eq!(header.doctype_read_version.value(), 2);
+        assert_eq!(header.doctype_read_version.source(), ValueSource::Explicit);
+        assert_eq!(header.doctype_version.value(), 1);
+        assert_eq!(header.doctype_version.source(), ValueSource::Absent);
+        assert_eq!(header.ebml_version.value(), 1);
+        assert_eq!(header.max_size_length.value(), 8);
+        assert_eq!(header.max_size_length.source(), ValueSource::Absent);
+
+        // Empty elements without a schema default take the empty value of their type
+        let bytes = Element::Raw {
+            id: CODEC_ID_ID,
+            data: vec![],
+        }
+        .to_bytes()
+        .unwrap();
+        let mut reader = MatroskaReader::new(Cursor::new(bytes));
+        let root = ebml::read_root(&mut reader.ebml_reader, &MatroskaSchema).unwrap();
+        let codec = Field::parse_string(&mut reader, &root[0]).unwrap();
+        assert_eq!(codec.value, "");
+        assert_eq!(codec.source, ValueSource::Explicit);
+    }
+
+    #[test]
+    fn test_parse_diagnostics() {
+        let bytes = Element::Root {
+            children: vec![
+                Element::Master {
+                    id: EBML_HEADER_ID,
+                    children: vec![
+                        Element::Raw {
+                            id: EBML_HEADER_DOCTYPE_ID,
+                            data: b"matroska".to_vec(),
+                        },
+                        Element::Raw {
+                            id: 0xEC, // Void
+                            data: vec![0x00, 0x00],
+                        },
+                    ],
+                },
+                Element::Master {
+                    id: SEGMENT_ID,
+                    children: vec![
+                        Element::Master {
+                            id: INFO_ID,
+                            children: vec![],
+                        },
+                        Element::Master {
+                            id: CUES_ID,
+                            children: vec![],
+                        },
+                    ],
+                },
+            ],
+        }
+        .to_bytes()
+        .unwrap();
+
+        let mut diagnostics = Vec::new();
+        MatroskaDocument::parse_with(
+            Cursor::new(bytes.clone()),
+            ReaderLimits::default(),
+            &mut diagnostics,
+        )
+        .unwrap();
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].code, DiagnosticCode::UnhandledElement);
+        assert_eq!(diagnostics[0].offset, 16);
+        assert_eq!(diagnostics[0].path.to_string(), "EBML/Void");
+        assert_eq!(
+            diagnostics[1].to_string(),
+            "warning: unhandled element Cues (0x1C53BB6B) at offset 30 in Segment/Cues"
+        );
+
+        // Promote warnings about elements inside the Segment to errors
+        let sink = diagnostics::DiagnosticFn(|diagnostic: Diagnostic| {
+            if diagnostic.path.ids().next() == Some(SEGMENT_ID) {
+                Err(diagnostic)
+            } else {
+                Ok(())
+            }
+        });
+        let error = MatroskaDocument::parse_with(Cursor::new(bytes), ReaderLimits::default(), sink)
+            .unwrap_err();
+        assert!(matches!(error, MatroskaParseError::Diagnostic(_)));
+        assert_eq!(error.offset(), Some(30));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_parse_from_async() {
+        crate::ebml::async_reader::block_on(async {
+            let bytes = Element::Root {
+                children: vec![
+                    Element::Master {
+                        id: EBML_HEADER_ID,
+                        children: vec![Element::Raw {
+                            id: EBML_HEADER_DOCTYPE_ID,
+                            data: b"matroska".to_vec(),
+                        }],
+                    },
+                    Element::Master {
+                        id: SEGMENT_ID,
+                        children: vec![
+                            Element::Master {
+                                id: INFO_ID,
+                                children: vec![],
+                            },
+                            Element::Master {
+                                id: CLUSTER_ID,
+                                children: vec![Element::Raw {
+                                    id: TIMESTAMP_ID,
+                                    data: vec![0x01],
+                                }],
+                            },
+                        ],
+                    },
+                ],
+            }
+            .to_bytes()
+            .unwrap();
+            let document = MatroskaDocument::parse_from_async(Cursor::new(bytes.clone()))
+                .await
+                .unwrap();
+            assert_eq!(document.ebml_header.doctype.value, "matroska");
+            assert_eq!(document.segment.raw.children.unwrap().len(), 2);
+
+            let mut diagnostics = Vec::new();
+            MatroskaDocument::parse_from_async_with(
+                Cursor::new(bytes),
+                ReaderLimits::default(),
+                &mut diagnostics,
+            )
+            .await
+            .unwrap();
+            assert_eq!(diagnostics.len(), 1);
+            assert_eq!(
+                diagnostics[0].path.ids().collect::<Vec<_>>(),
+                [SEGMENT_ID, CLUSTER_ID]
+            );
+        });
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_parse_from_async_skips_attachments() {
+        crate::ebml::async_reader::block_on(async {
+            let bytes = Element::Root {
+                children: vec![
+                    Element::Master {
+                        id: EBML_HEADER_ID,
+                        children: vec![Element::Raw {
+                            id: EBML_HEADER_DOCTYPE_ID,
+                            data: b"matroska".to_vec(),
+                        }],
+                    },
+                    Element::Master {
+                        id: SEGMENT_ID,
+                        children: vec![
+                            Element::Master {
+                                id: INFO_ID,
+                                children: vec![],
+                            },
+                            Element::Master {
+                                id: ATTACHMENTS_ID,
+                                children: vec![Element::Master {
+                                    id: ATTACHED_FILE_ID,
+                                    children: vec![Element::Raw {
+                                        id: FILE_DATA_ID,
+                                        data: vec![0; 1024],
+                                    }],
+                                }],
+                            },
+                        ],
+                    },
+                ],
+            }
+            .to_bytes()
+            .unwrap();
+            // The attachment exceeds the payload limit, but is never read
+            let limits = ReaderLimits {
+                max_payload_size: 64,
+                ..ReaderLimits::default()
+            };
+            MatroskaDocument::parse_with_limits(Cursor::new(bytes.clone()), limits).unwrap();
+            let document = MatroskaDocument::parse_from_async_with(
+                Cursor::new(bytes),
+                limits,
+                IgnoreDiagnostics,
+            )
+            .await
+            .unwrap();
+            assert_eq!(document.segment.raw.children.unwrap().len(), 2);
+        });
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        let mut bytes = Element::Root {
+            children: vec![
+                Element::Master {
+                    id: EBML_HEADER_ID,
+                    children: vec![Element::Raw {
+                        id: EBML_HEADER_DOCTYPE_ID,
+                        data: b"matroska".to_vec(),
+                    }],
+                },
+                Element::Master {
+                    id: SEGMENT_ID,
+                    children: vec![
+                        Element::Master {
+                            id: INFO_ID,
+                            children: vec![],
+                        },
+                        Element::Master {
+                            id: CLUSTER_ID,
+                            children: vec![Element::Raw {
+                                id: TIMESTAMP_ID,
+                                data: vec![0x01, 0x02],
+                            }],
+                        },
+                        Element::Master {
+                            id: CUES_ID,
+                            children: vec![],
+                        },
+                    ],
+                },
+            ],
+        }
+        .to_bytes()
+        .unwrap();
+        // Corrupt the Timestamp ID
+        let timestamp = bytes.len() - 9;
+        bytes[timestamp] = 0x00;
+
+        assert!(MatroskaDocument::parse_from(Cursor::new(bytes.clone())).is_err());
+        let (document, damaged) =
+            MatroskaDocument::parse_lenient(Cursor::new(bytes.clone())).unwrap();
+        let segment_children = document.segment.raw.children.unwrap();
+        assert_eq!(segment_children.len(), 2);
+        assert_eq!(segment_children[1].id, CUES_ID);
+        assert_eq!(damaged.len(), 1);
+        assert_eq!(damaged[0].range.length, 9);
+
+        // The unhandled Cues can be promoted to an error
+        let sink = diagnostics::DiagnosticFn(|diagnostic: Diagnostic| Err(diagnostic));
+        let error =
+            MatroskaDocument::parse_lenient_with(Cursor::new(bytes), ReaderLimits::default(), sink)
+                .unwrap_err();
+        assert!(matches!(
+            error,
+            MatroskaParseError::Diagnostic(Diagnostic {
+                code: DiagnosticCode::UnhandledElement,
+                ..
+            })
+        ));
+    }
+
+    fn header_bytes(doctype: &[u8], read_version: u8, extension: bool) -> Vec<u8> {
+        let mut children = vec![
+            Element::Raw {
+                id: EBML_HEADER_DOCTYPE_ID,
+                data: doctype.to_vec(),
+            },
+            Element::Raw {
+                id: EBML_HEADER_DOCTYPE_READ_VERSION_ID,
+                data: vec![read_version],
+            },
+        ];
+        if extension {
+            children.push(Element::Master {
+                id: EBML_HEADER_DOCTYPE_EXTENSION_ID,
+                children: vec![
+                    Element::Raw {
+                        id: EBML_HEADER_DOCTYPE_EXTENSION_NAME_ID,
+                        data: b"mkvedit".to_vec(),
+                    },
+                    Element::Raw {
+                        id: EBML_HEADER_DOCTYPE_EXTENSION_VERSION_ID,
+                        data: vec![0x02],
+                    },
+                ],
+            });
+        }
+        Element::Root {
+            children: vec![
+                Element::Master {
+                    id: EBML_HEADER_ID,
+                    children,
+                },
+                Element::Master {
+                    id: SEGMENT_ID,
+                    children: vec![Element::Master {
+                        id: INFO_ID,
+                        children: vec![],
+                    }],
+                },
+            ],
+        }
+        .to_bytes()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_doctypes() {
+        let document =
+            MatroskaDocument::parse_from(Cursor::new(header_bytes(b"webm", 2, true))).unwrap();
+        assert_eq!(document.doctype, DocType::WebM);
+        let header = &document.ebml_header;
+        assert_eq!(header.ebml_version.value(), 1);
+        assert_eq!(header.ebml_read_version.source(), ValueSource::Absent);
+        assert_eq!(header.doctype_extensions.len(), 1);
+        assert_eq!(header.doctype_extensions[0].name.value, "mkvedit");
+        assert_eq!(header.doctype_extensions[0].version.value, 2);
+
+        let document =
+            MatroskaDocument::parse_from(Cursor::new(header_bytes(b"matroska", 4, false))).unwrap();
+        assert_eq!(document.doctype, DocType::Matroska);
+
+        let error = MatroskaDocument::parse_from(Cursor::new(header_bytes(b"matroska", 5, false)))
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            MatroskaParseError::UnsupportedReadVersion {
+                doctype: DocType::Matroska,
+                read_version: 5,
+                supported: 4,
+            }
+        ));
+        assert_eq!(error.offset(), Some(0));
+
+        // The header itself is doctype-agnostic
+        let bytes = header_bytes(b"x-custom", 1, false);
+        let error = MatroskaDocument::parse_from(Cursor::new(bytes.clone())).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            MatroskaParseError::UnsupportedDocType(doctype) if doctype == "x-custom"
+        ));
+        let mut reader = MatroskaReader::new(Cursor::new(bytes));
+        let root = ebml::read_root(&mut reader.ebml_reader, &MatroskaSchema).unwrap();
+        let header: EbmlHeader = reader.parse_element(&root[0]).unwrap();
+        assert_eq!(header.doctype.value, "x-custom");
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let header = Element::Master {
+            id: EBML_HEADER_ID,
+            children: vec![Element::Raw {
+                id: EBML_HEADER_DOCTYPE_ID,
+                data: b"matroska".to_vec(),
+            }],
+        };
+        let bytes = Element::Root {
+            children: vec![
+                header,
+                Element::Master {
+                    id: SEGMENT_ID,
+                    children: vec![],
+                },
+            ],
+        }
+        .to_bytes()
+        .unwrap();
+        let error = MatroskaDocument::parse_from(Cursor::new(bytes)).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            MatroskaParseError::MissingElement("Info")
+        ));
+        assert_eq!(error.offset(), Some(16));
+        assert_eq!(
+            error.to_string(),
+            "missing required element: Info at offset 16 in Segment"
+        );
+
+        let bytes = Element::Master {
+            id: EBML_HEADER_ID,
+            children: vec![Element::Raw {
+                id: EBML_HEADER_VERSION_ID,
+                data: vec![0; 9],
+            }],
+        }
+        .to_bytes()
+        .unwrap();
+        let mut reader = MatroskaReader::new(Cursor::new(bytes));
+        let root = ebml::read_root(&mut reader.ebml_reader, &MatroskaSchema).unwrap();
+        let error = reader.parse_element::<EbmlHeader>(&root[0]).unwrap_err();
+        assert_eq!(error.offset(), Some(5));
+        assert_eq!(error.path().unwrap().to_string(), "EBML/EBMLVersion");
+
+        // Truncated inside a Cluster, as when piped input ends early
+        let mut bytes = Element::Master {
+            id: SEGMENT_ID,
+            children: vec![Element::Master {
+                id: CLUSTER_ID,
+                children: vec![Element::Raw {
+                    id: TIMESTAMP_ID,
+                    data: vec![0x01, 0x02],
+                }],
+            }],
+        }
+        .to_bytes()
+        .unwrap();
+        bytes.pop();
+        let mut reader = ebml::EbmlStreamReader::new(bytes.as_slice());
+        let error = reader.read_root(&MatroskaSchema).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            EbmlError::UnexpectedEof("skipping element data")
+        ));
+        assert_eq!(error.offset(), Some(10));
+        assert_eq!(
+            error.to_string(),
+            "unexpected EOF: skipping element data at offset 10 in Segment/Cluster"
+        );
+    }
+
+    #[derive(Debug, MatroskaElement)]
+    #[matroska(id = INFO_ID)]
+    struct TestInfo {
+        raw: ParsedElement,
+        #[matroska(id = TIMESTAMP_SCALE_ID, default = 1_000_000)]
+        timestamp_scale: OptionalField<u64>,
+        #[matroska(id = MUXING_APP_ID)]
+        muxing_app: Field<String>,
+        #[matroska(id = TITLE_ID)]
+        title: Option<Field<String>>,
+        #[matroska(id = CHAPTER_TRANSLATE_ID)]
+        chapter_translates: Vec<TestChapterTranslate>,
+    }
+
+    #[derive(Debug, MatroskaElement)]
+    #[matroska(id = CHAPTER_TRANSLATE_ID)]
+    struct TestChapterTranslate {
+        raw: ParsedElement,
+        #[matroska(id = CHAPTER_TRANSLATE_ID_ID)]
+        id: Field<Vec<u8>>,
+        #[matroska(id = CHAPTER_TRANSLATE_EDITION_UID_ID)]
+        edition_uids: Vec<Field<u64>>,
+    }
+
+    #[test]
+    fn test_derive_element() {
+        let chapter_translate = |id: u8| Element::Master {
+            id: CHAPTER_TRANSLATE_ID,
+            children: vec![
+                Element::Raw {
+                    id: CHAPTER_TRANSLATE_ID_ID,
+                    data: vec![id],
+                },
+                Element::Raw {
+                    id: CHAPTER_TRANSLATE_EDITION_UID_ID,
+                    data: vec![0x01],
+                },
+                Element::Raw {
+                    id: CHAPTER_TRANSLATE_EDITION_UID_ID,
+                    data: vec![0x02],
+                },
+            ],
+        };
+        let bytes = Element::Master {
+            id: INFO_ID,
+            children: vec![
+                Element::Raw {
+                    id: MUXING_APP_ID,
+                    data: "mkvédit".as_bytes().to_vec(),
+                },
+                chapter_translate(0xA),
+                chapter_translate(0xB),
+            ],
+        }
+        .to_bytes()
+        .unwrap();
+
+        let mut reader = MatroskaReader::new(Cursor::new(bytes.clone()));
+        let root = ebml::read_root(&mut reader.ebml_reader, &MatroskaSchema).unwrap();
+        let info: TestInfo = reader.parse_element(&root[0]).unwrap();
+        assert_eq!(info.timestamp_scale.value(), 1_000_000);
+        assert_eq!(info.timestamp_scale.source(), ValueSource::Absent);
+        assert_eq!(info.muxing_app.value, "mkvédit");
+        assert!(info.title.is_none());
+        assert_eq!(info.chapter_translates.len(), 2);
+        assert_eq!(info.chapter_translates[1].id.value, vec![0xB]);
+        assert_eq!(info.chapter_translates[1].edition_uids[1].value, 2);
+
+        // Absent defaults are not written and all children have a field,
+        // so the element round-trips
+        assert_eq!(info.to_element().unwrap().to_bytes().unwrap(), bytes);
+
+        let mut out = String::new();
+        info.print_tree(&mut out, &mut TreePrinter::new(), true, false)
+            .unwrap();
+        assert_eq!(
+            out.lines().take(3).collect::<Vec<_>>(),
+            [
+                "└── Info",
+                "    ├── MuxingApp: \"mkvédit\"",
+                "    ├── ChapterTranslate",
+            ]
+        );
+
+        let bytes = Element::Master {
+            id: INFO_ID,
+            children: vec![],
+        }
+        .to_bytes()
+        .unwrap();
+        let mut reader = MatroskaReader::new(Cursor::new(bytes));
+        let root = ebml::read_root(&mut reader.ebml_reader, &MatroskaSchema).unwrap();
+        let error = reader.parse_element::<TestInfo>(&root[0]).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            MatroskaParseError::MissingElement("MuxingApp")
+        ));
+    }
+
+    #[test]
+    fn test_derive_element_order() {
+        let title = || Element::Raw {
+            id: TITLE_ID,
+            data: b"title".to_vec(),
+        };
+        let muxing_app = || Element::Raw {
+            id: MUXING_APP_ID,
+            data: b"mkvmerge".to_vec(),
+        };
+        let timestamp_scale = || Element::Raw {
+            id: TIMESTAMP_SCALE_ID,
+            data: vec![0x0F, 0x42, 0x40],
+        };
+        let info = |children| Element::Master {
+            id: INFO_ID,
+            children,
+        };
+        let bytes = info(vec![
+            title(),
+            Element::Raw {
+                id: 0xEC,
+                data: vec![0; 2],
+            },
+            muxing_app(),
+            timestamp_scale(),
+        ])
+        .to_bytes()
+        .unwrap();
+
+        let mut reader = MatroskaReader::new(Cursor::new(bytes));
+        let root = ebml::read_root(&mut reader.ebml_reader, &MatroskaSchema).unwrap();
+        let parsed: TestInfo = reader.parse_element(&root[0]).unwrap();
+        // Children keep their original order, but the Void element has no field and is dropped
+        assert_eq!(
+            parsed.to_element().unwrap().to_bytes().unwrap(),
+            info(vec![title(), muxing_app(), timestamp_scale()])
+                .to_bytes()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_info_fields() {
+        let raw = |id, data: &[u8]| Element::Raw {
+            id,
+            data: data.to_vec(),
+        };
+        let uuid = |byte: u8| [byte; 16];
+        let bytes = Element::Master {
+            id: INFO_ID,
+            children: vec![
+                raw(SEGMENT_UUID_ID, &uuid(0x01)),
+                raw(SEGMENT_FILENAME_ID, "part 2.mkv".as_bytes()),
+                raw(PREV_UUID_ID, &uuid(0x02)),
+                raw(PREV_FILENAME_ID, b"part 1.mkv"),
+                raw(NEXT_UUID_ID, &uuid(0x03)),
+                raw(NEXT_FILENAME_ID, b"part 3.mkv"),
+                raw(SEGMENT_FAMILY_ID, &uuid(0x04)),
+                Element::Master {
+                    id: CHAPTER_TRANSLATE_ID,
+                    children: vec![
+                        raw(CHAPTER_TRANSLATE_ID_ID, &[0x0A]),
+                        raw(CHAPTER_TRANSLATE_CODEC_ID, &[0x01]),
+                        raw(CHAPTER_TRANSLATE_EDITION_UID_ID, &[0x02]),
+                    ],
+                },
+                raw(TIMESTAMP_SCALE_ID, &[0x07, 0xA1, 0x20]), // 500000
+                raw(DURATION_ID, &2.5f64.to_be_bytes()),
+                raw(DATE_UTC_ID, &1_000_000_000i64.to_be_bytes()),
+                raw(TITLE_ID, "Épisode".as_bytes()),
+            ],
+        }
+        .to_bytes()
+        .unwrap();
+
+        let mut reader = MatroskaReader::new(Cursor::new(bytes.clone()));
+        let root = ebml::read_root(&mut reader.ebml_reader, &MatroskaSchema).unwrap();
+        let info: Info = reader.parse_element(&root[0]).unwrap();
+        assert_eq!(info.segment_uuid.as_ref().unwrap().value, uuid(0x01));
+        assert_eq!(info.segment_filename.as_ref().unwrap().value, "part 2.mkv");
+        assert_eq!(info.prev_uuid.as_ref().unwrap().value, uuid(0x02));
+        assert_eq!(info.prev_filename.as_ref().unwrap().value, "part 1.mkv");
+        assert_eq!(info.next_uuid.as_ref().unwrap().value, uuid(0x03));
+        assert_eq!(info.next_filename.as_ref().unwrap().value, "part 3.mkv");
+        assert_eq!(info.segment_families[0].value, uuid(0x04));
+        let chapter_translate = &info.chapter_translates[0];
+        assert_eq!(chapter_translate.id.value, vec![0x0A]);
+        assert_eq!(chapter_translate.codec.value, 1);
+        assert_eq!(chapter_translate.edition_uids[0].value, 2);
+        assert_eq!(info.timestamp_scale.value(), 500_000);
+        assert_eq!(info.timestamp_scale.source(), ValueSource::Explicit);
+        assert!((info.duration.as_ref().unwrap().value - 2.5).abs() < f64::EPSILON);
+        assert_eq!(
+            info.date_utc.as_ref().unwrap().value,
+            EbmlDate::new(1_000_000_000)
+        );
+        assert_eq!(info.title.as_ref().unwrap().value, "Épisode");
+        assert!(info.muxing_app.is_none() && info.writing_app.is_none());
+
+        assert_eq!(info.to_element().unwrap().to_bytes().unwrap(), bytes);
+
+        let mut out = String::new();
+        info.print_tree(&mut out, &mut TreePrinter::new(), true, false)
+            .unwrap();
+        let lines: Vec<_> = out.lines().collect();
+        assert_eq!(
+            lines[1],
+            "    ├── SegmentUUID: 0x01010101010101010101010101010101"
+        );
+        assert_eq!(
+            lines[8..12],
+            [
+                "    ├── ChapterTranslate",
+                "    │   ├── ChapterTranslateID: 0x0A",
+                "    │   ├── ChapterTranslateCodec: 1",
+                "    │   └── ChapterTranslateEditionUID: 2",
+            ]
+        );
+        assert_eq!(lines[12], "    ├── TimestampScale: 500000");
+        assert_eq!(lines[13], "    ├── Duration: 2.5");
+        assert_eq!(lines[14], "    ├── DateUTC: 2001-01-01T00:00:01Z");
+        assert_eq!(lines[15], "    └── Title: \"Épisode\"");
+    }
+
+    #[test]
+    fn test_parse_tracks() {
+        let uint = |id, value: u8| Element::Raw {
+            id,
+            data: vec![value],
+        };
+        let string = |id, value: &str| Element::Raw {
+            id,
+            data: value.as_bytes().to_vec(),
+        };
+        let bytes = Element::Master {
+            id: TRACKS_ID,
+            children: vec![
+                Element::Master {
+                    id: TRACK_ENTRY_ID,
+                    children: vec![
+                        uint(TRACK_NUMBER_ID, 1),
+                        uint(TRACK_UID_ID, 0x10),
+                        uint(TRACK_TYPE_ID, 2),
+                        uint(FLAG_DEFAULT_ID, 0),
+                        uint(FLAG_ORIGINAL_ID, 1),
+                        string(NAME_ID, "Commentaire"),
+                        string(LANGUAGE_ID, "fre"),
+                        string(LANGUAGE_BCP47_ID, "fr-CA"),
+                        string(CODEC_ID_ID, "A_OPUS"),
+                        Element::Raw {
+                            id: CODEC_PRIVATE_ID,
+                            data: vec![0x4F, 0x70],
+                        },
+                        Element::Raw {
+                            id: CODEC_DELAY_ID,
+                            data: vec![0x63, 0x2E, 0xA0], // 6500000
+                        },
+                    ],
+                },
+                Element::Master {
+                    id: TRACK_ENTRY_ID,
+                    children: vec![
+                        uint(TRACK_NUMBER_ID, 2),
+                        uint(TRACK_UID_ID, 0x20),
+                        uint(TRACK_TYPE_ID, 1),
+                        string(CODEC_ID_ID, "V_VP9"),
+                    ],
+                },
+            ],
+        }
+        .to_bytes()
+        .unwrap();
+
+        let mut reader = MatroskaReader::new(Cursor::new(bytes.clone()));
+        let root = ebml::read_root(&mut reader.ebml_reader, &MatroskaSchema).unwrap();
+        let tracks: Tracks = reader.parse_element(&root[0]).unwrap();
+        assert_eq!(tracks.entries.len(), 2);
+
+        let audio = &tracks.entries[0];
+        assert_eq!(audio.track_type.value, 2);
+        assert_eq!(audio.flag_default.value(), 0);
+        assert_eq!(audio.flag_default.source(), ValueSource::Explicit);
+        assert_eq!(audio.flag_original.as_ref().unwrap().value, 1);
+        assert!(audio.flag_commentary.is_none());
+        assert_eq!(audio.language.value_ref(), "fre");
+        assert_eq!(audio.language_bcp47.as_ref().unwrap().value, "fr-CA");
+        assert_eq!(
+            audio.codec_private.as_ref().unwrap().value,
+            vec![0x4F, 0x70]
+        );
+        assert_eq!(audio.codec_delay.value(), 6_500_000);
+
+        let video = &tracks.entries[1];
+        assert_eq!(video.codec_id.value, "V_VP9");
+        assert_eq!(video.flag_enabled.value(), 1);
+        assert_eq!(video.flag_default.value(), 1);
+        assert_eq!(video.flag_forced.value(), 0);
+        assert_eq!(video.flag_lacing.value(), 1);
+        assert_eq!(video.language.value_ref(), "eng");
+        assert_eq!(video.language.source(), ValueSource::Absent);
+        assert!(video.name.is_none() && video.default_duration.is_none());
+
+        assert_eq!(tracks.to_element().unwrap().to_bytes().unwrap(), bytes);
+
+        let mut out = String::new();
+        tracks
+            .print_tree(&mut out, &mut TreePrinter::new(), true, false)
+            .unwrap();
+        assert_eq!(
+            out,
+            "└── Tracks
+    ├── TrackEntry
+    │   ├── TrackNumber: 1
+    │   ├── TrackUID: 16
+    │   ├── TrackType: 2
+    │   ├── FlagDefault: 0
+    │   ├── FlagOriginal: 1
+    │   ├── Name: \"Commentaire\"
+    │   ├── Language: \"fre\"
+    │   ├── LanguageBCP47: \"fr-CA\"
+    │   ├── CodecID: \"A_OPUS\"
+    │   ├── CodecPrivate: 0x4F70
+    │   └── CodecDelay: 6500000
+    └── TrackEntry
+        ├── TrackNumber: 2
+        ├── TrackUID: 32
+        ├── TrackType: 1
+        └── CodecID: \"V_VP9\"
+"
+        );
+    }
+
+    #[test]
+    fn test_parse_track_entry_without_codec() {
+        let uint = |id, value: u8| Element::Raw {
+            id,
+            data: vec![value],
+        };
+        let bytes = Element::Master {
+            id: TRACK_ENTRY_ID,
+            children: vec![
+                uint(TRACK_NUMBER_ID, 1),
+                uint(TRACK_UID_ID, 1),
+                uint(TRACK_TYPE_ID, 1),
+            ],
+        }
+        .to_bytes()
+        .unwrap();
+        let mut reader = MatroskaReader::new(Cursor::new(bytes));
+        let root = ebml::read_root(&mut reader.ebml_reader, &MatroskaSchema).unwrap();
+        let error = reader.parse_element::<TrackEntry>(&root[0]).unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            MatroskaParseError::MissingElement("CodecID")
+        ));
+    }
+}
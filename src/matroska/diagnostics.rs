@@ -0,0 +1,87 @@
+use std::fmt;
+
+use crate::ebml::error::ElementPath;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+// Kind of a diagnostic, for filtering without matching on messages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticCode {
+    // A child element the parser does not handle, which is skipped
+    UnhandledElement,
+}
+
+// A problem found while parsing which does not stop parsing by itself
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: DiagnosticCode,
+    pub message: String,
+    // Start of the element header the diagnostic is about
+    pub offset: u64,
+    // Path of the element the diagnostic is about, including the element itself
+    pub path: ElementPath,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} at offset {} in {}",
+            self.severity, self.message, self.offset, self.path
+        )
+    }
+}
+
+// Receives the diagnostics of a `MatroskaReader`
+pub trait DiagnosticSink {
+    // Returning the diagnostic as an error aborts parsing with `MatroskaParseError::Diagnostic`
+    fn report(&mut self, diagnostic: Diagnostic) -> Result<(), Diagnostic>;
+}
+
+// Discards all diagnostics, the default sink
+pub struct IgnoreDiagnostics;
+
+impl DiagnosticSink for IgnoreDiagnostics {
+    fn report(&mut self, _: Diagnostic) -> Result<(), Diagnostic> {
+        Ok(())
+    }
+}
+
+// Collects all diagnostics
+impl DiagnosticSink for Vec<Diagnostic> {
+    fn report(&mut self, diagnostic: Diagnostic) -> Result<(), Diagnostic> {
+        self.push(diagnostic);
+        Ok(())
+    }
+}
+
+impl<T: DiagnosticSink + ?Sized> DiagnosticSink for &mut T {
+    fn report(&mut self, diagnostic: Diagnostic) -> Result<(), Diagnostic> {
+        (**self).report(diagnostic)
+    }
+}
+
+// Sink calling a closure, e.g. to filter diagnostics or promote some of them to errors
+pub struct DiagnosticFn<F: FnMut(Diagnostic) -> Result<(), Diagnostic>>(pub F);
+
+impl<F: FnMut(Diagnostic) -> Result<(), Diagnostic>> DiagnosticSink for DiagnosticFn<F> {
+    fn report(&mut self, diagnostic: Diagnostic) -> Result<(), Diagnostic> {
+        (self.0)(diagnostic)
+    }
+}
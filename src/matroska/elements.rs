@@ -0,0 +1,664 @@
+// Generated by `cargo run --example=generate_matroska_elements -- ebml_matroska.xml src/matroska/elements.rs`
+// from the EBML schema for docType "matroska" version 4, do not edit manually
+
+use std::borrow::Cow;
+
+use crate::ebml::schema::{ElementDef, ElementType};
+
+pub const SEGMENT_ID: u64 = 0x1853_8067;
+pub const SEEK_HEAD_ID: u64 = 0x114D_9B74;
+pub const SEEK_ID: u64 = 0x4DBB;
+pub const SEEK_ID_ID: u64 = 0x53AB;
+pub const SEEK_POSITION_ID: u64 = 0x53AC;
+pub const INFO_ID: u64 = 0x1549_A966;
+pub const SEGMENT_UUID_ID: u64 = 0x73A4;
+pub const SEGMENT_FILENAME_ID: u64 = 0x7384;
+pub const PREV_UUID_ID: u64 = 0x003C_B923;
+pub const PREV_FILENAME_ID: u64 = 0x003C_83AB;
+pub const NEXT_UUID_ID: u64 = 0x003E_B923;
+pub const NEXT_FILENAME_ID: u64 = 0x003E_83BB;
+pub const SEGMENT_FAMILY_ID: u64 = 0x4444;
+pub const CHAPTER_TRANSLATE_ID: u64 = 0x6924;
+pub const CHAPTER_TRANSLATE_ID_ID: u64 = 0x69A5;
+pub const CHAPTER_TRANSLATE_CODEC_ID: u64 = 0x69BF;
+pub const CHAPTER_TRANSLATE_EDITION_UID_ID: u64 = 0x69FC;
+pub const TIMESTAMP_SCALE_ID: u64 = 0x002A_D7B1;
+pub const DURATION_ID: u64 = 0x4489;
+pub const DATE_UTC_ID: u64 = 0x4461;
+pub const TITLE_ID: u64 = 0x7BA9;
+pub const MUXING_APP_ID: u64 = 0x4D80;
+pub const WRITING_APP_ID: u64 = 0x5741;
+pub const CLUSTER_ID: u64 = 0x1F43_B675;
+pub const TIMESTAMP_ID: u64 = 0xE7;
+pub const POSITION_ID: u64 = 0xA7;
+pub const PREV_SIZE_ID: u64 = 0xAB;
+pub const SIMPLE_BLOCK_ID: u64 = 0xA3;
+pub const BLOCK_GROUP_ID: u64 = 0xA0;
+pub const BLOCK_ID: u64 = 0xA1;
+pub const BLOCK_ADDITIONS_ID: u64 = 0x75A1;
+pub const BLOCK_MORE_ID: u64 = 0xA6;
+pub const BLOCK_ADDITIONAL_ID: u64 = 0xA5;
+pub const BLOCK_ADD_ID_ID: u64 = 0xEE;
+pub const BLOCK_DURATION_ID: u64 = 0x9B;
+pub const REFERENCE_PRIORITY_ID: u64 = 0xFA;
+pub const REFERENCE_BLOCK_ID: u64 = 0xFB;
+pub const CODEC_STATE_ID: u64 = 0xA4;
+pub const DISCARD_PADDING_ID: u64 = 0x75A2;
+pub const TRACKS_ID: u64 = 0x1654_AE6B;
+pub const TRACK_ENTRY_ID: u64 = 0xAE;
+pub const TRACK_NUMBER_ID: u64 = 0xD7;
+pub const TRACK_UID_ID: u64 = 0x73C5;
+pub const TRACK_TYPE_ID: u64 = 0x83;
+pub const FLAG_ENABLED_ID: u64 = 0xB9;
+pub const FLAG_DEFAULT_ID: u64 = 0x88;
+pub const FLAG_FORCED_ID: u64 = 0x55AA;
+pub const FLAG_HEARING_IMPAIRED_ID: u64 = 0x55AB;
+pub const FLAG_VISUAL_IMPAIRED_ID: u64 = 0x55AC;
+pub const FLAG_TEXT_DESCRIPTIONS_ID: u64 = 0x55AD;
+pub const FLAG_ORIGINAL_ID: u64 = 0x55AE;
+pub const FLAG_COMMENTARY_ID: u64 = 0x55AF;
+pub const FLAG_LACING_ID: u64 = 0x9C;
+pub const DEFAULT_DURATION_ID: u64 = 0x0023_E383;
+pub const DEFAULT_DECODED_FIELD_DURATION_ID: u64 = 0x0023_4E7A;
+pub const TRACK_TIMESTAMP_SCALE_ID: u64 = 0x0023_314F;
+pub const MAX_BLOCK_ADDITION_ID_ID: u64 = 0x55EE;
+pub const BLOCK_ADDITION_MAPPING_ID: u64 = 0x41E4;
+pub const BLOCK_ADD_ID_VALUE_ID: u64 = 0x41F0;
+pub const BLOCK_ADD_ID_NAME_ID: u64 = 0x41A4;
+pub const BLOCK_ADD_ID_TYPE_ID: u64 = 0x41E7;
+pub const BLOCK_ADD_ID_EXTRA_DATA_ID: u64 = 0x41ED;
+pub const NAME_ID: u64 = 0x536E;
+pub const LANGUAGE_ID: u64 = 0x0022_B59C;
+pub const LANGUAGE_BCP47_ID: u64 = 0x0022_B59D;
+pub const CODEC_ID_ID: u64 = 0x86;
+pub const CODEC_PRIVATE_ID: u64 = 0x63A2;
+pub const CODEC_NAME_ID: u64 = 0x0025_8688;
+pub const ATTACHMENT_LINK_ID: u64 = 0x7446;
+pub const CODEC_DELAY_ID: u64 = 0x56AA;
+pub const SEEK_PRE_ROLL_ID: u64 = 0x56BB;
+pub const TRACK_TRANSLATE_ID: u64 = 0x6624;
+pub const TRACK_TRANSLATE_TRACK_ID_ID: u64 = 0x66A5;
+pub const TRACK_TRANSLATE_CODEC_ID: u64 = 0x66BF;
+pub const TRACK_TRANSLATE_EDITION_UID_ID: u64 = 0x66FC;
+pub const VIDEO_ID: u64 = 0xE0;
+pub const FLAG_INTERLACED_ID: u64 = 0x9A;
+pub const FIELD_ORDER_ID: u64 = 0x9D;
+pub const STEREO_MODE_ID: u64 = 0x53B8;
+pub const ALPHA_MODE_ID: u64 = 0x53C0;
+pub const PIXEL_WIDTH_ID: u64 = 0xB0;
+pub const PIXEL_HEIGHT_ID: u64 = 0xBA;
+pub const PIXEL_CROP_BOTTOM_ID: u64 = 0x54AA;
+pub const PIXEL_CROP_TOP_ID: u64 = 0x54BB;
+pub const PIXEL_CROP_LEFT_ID: u64 = 0x54CC;
+pub const PIXEL_CROP_RIGHT_ID: u64 = 0x54DD;
+pub const DISPLAY_WIDTH_ID: u64 = 0x54B0;
+pub const DISPLAY_HEIGHT_ID: u64 = 0x54BA;
+pub const DISPLAY_UNIT_ID: u64 = 0x54B2;
+pub const UNCOMPRESSED_FOUR_CC_ID: u64 = 0x002E_B524;
+pub const COLOUR_ID: u64 = 0x55B0;
+pub const MATRIX_COEFFICIENTS_ID: u64 = 0x55B1;
+pub const BITS_PER_CHANNEL_ID: u64 = 0x55B2;
+pub const CHROMA_SUBSAMPLING_HORZ_ID: u64 = 0x55B3;
+pub const CHROMA_SUBSAMPLING_VERT_ID: u64 = 0x55B4;
+pub const CB_SUBSAMPLING_HORZ_ID: u64 = 0x55B5;
+pub const CB_SUBSAMPLING_VERT_ID: u64 = 0x55B6;
+pub const CHROMA_SITING_HORZ_ID: u64 = 0x55B7;
+pub const CHROMA_SITING_VERT_ID: u64 = 0x55B8;
+pub const RANGE_ID: u64 = 0x55B9;
+pub const TRANSFER_CHARACTERISTICS_ID: u64 = 0x55BA;
+pub const PRIMARIES_ID: u64 = 0x55BB;
+pub const MAX_CLL_ID: u64 = 0x55BC;
+pub const MAX_FALL_ID: u64 = 0x55BD;
+pub const MASTERING_METADATA_ID: u64 = 0x55D0;
+pub const PRIMARY_R_CHROMATICITY_X_ID: u64 = 0x55D1;
+pub const PRIMARY_R_CHROMATICITY_Y_ID: u64 = 0x55D2;
+pub const PRIMARY_G_CHROMATICITY_X_ID: u64 = 0x55D3;
+pub const PRIMARY_G_CHROMATICITY_Y_ID: u64 = 0x55D4;
+pub const PRIMARY_B_CHROMATICITY_X_ID: u64 = 0x55D5;
+pub const PRIMARY_B_CHROMATICITY_Y_ID: u64 = 0x55D6;
+pub const WHITE_POINT_CHROMATICITY_X_ID: u64 = 0x55D7;
+pub const WHITE_POINT_CHROMATICITY_Y_ID: u64 = 0x55D8;
+pub const LUMINANCE_MAX_ID: u64 = 0x55D9;
+pub const LUMINANCE_MIN_ID: u64 = 0x55DA;
+pub const PROJECTION_ID: u64 = 0x7670;
+pub const PROJECTION_TYPE_ID: u64 = 0x7671;
+pub const PROJECTION_PRIVATE_ID: u64 = 0x7672;
+pub const PROJECTION_POSE_YAW_ID: u64 = 0x7673;
+pub const PROJECTION_POSE_PITCH_ID: u64 = 0x7674;
+pub const PROJECTION_POSE_ROLL_ID: u64 = 0x7675;
+pub const AUDIO_ID: u64 = 0xE1;
+pub const SAMPLING_FREQUENCY_ID: u64 = 0xB5;
+pub const OUTPUT_SAMPLING_FREQUENCY_ID: u64 = 0x78B5;
+pub const CHANNELS_ID: u64 = 0x9F;
+pub const BIT_DEPTH_ID: u64 = 0x6264;
+pub const EMPHASIS_ID: u64 = 0x52F1;
+pub const TRACK_OPERATION_ID: u64 = 0xE2;
+pub const TRACK_COMBINE_PLANES_ID: u64 = 0xE3;
+pub const TRACK_PLANE_ID: u64 = 0xE4;
+pub const TRACK_PLANE_UID_ID: u64 = 0xE5;
+pub const TRACK_PLANE_TYPE_ID: u64 = 0xE6;
+pub const TRACK_JOIN_BLOCKS_ID: u64 = 0xE9;
+pub const TRACK_JOIN_UID_ID: u64 = 0xED;
+pub const CONTENT_ENCODINGS_ID: u64 = 0x6D80;
+pub const CONTENT_ENCODING_ID: u64 = 0x6240;
+pub const CONTENT_ENCODING_ORDER_ID: u64 = 0x5031;
+pub const CONTENT_ENCODING_SCOPE_ID: u64 = 0x5032;
+pub const CONTENT_ENCODING_TYPE_ID: u64 = 0x5033;
+pub const CONTENT_COMPRESSION_ID: u64 = 0x5034;
+pub const CONTENT_COMP_ALGO_ID: u64 = 0x4254;
+pub const CONTENT_COMP_SETTINGS_ID: u64 = 0x4255;
+pub const CONTENT_ENCRYPTION_ID: u64 = 0x5035;
+pub const CONTENT_ENC_ALGO_ID: u64 = 0x47E1;
+pub const CONTENT_ENC_KEY_ID_ID: u64 = 0x47E2;
+pub const CONTENT_ENC_AES_SETTINGS_ID: u64 = 0x47E7;
+pub const AES_SETTINGS_CIPHER_MODE_ID: u64 = 0x47E8;
+pub const CUES_ID: u64 = 0x1C53_BB6B;
+pub const CUE_POINT_ID: u64 = 0xBB;
+pub const CUE_TIME_ID: u64 = 0xB3;
+pub const CUE_TRACK_POSITIONS_ID: u64 = 0xB7;
+pub const CUE_TRACK_ID: u64 = 0xF7;
+pub const CUE_CLUSTER_POSITION_ID: u64 = 0xF1;
+pub const CUE_RELATIVE_POSITION_ID: u64 = 0xF0;
+pub const CUE_DURATION_ID: u64 = 0xB2;
+pub const CUE_BLOCK_NUMBER_ID: u64 = 0x5378;
+pub const CUE_CODEC_STATE_ID: u64 = 0xEA;
+pub const CUE_REFERENCE_ID: u64 = 0xDB;
+pub const CUE_REF_TIME_ID: u64 = 0x96;
+pub const ATTACHMENTS_ID: u64 = 0x1941_A469;
+pub const ATTACHED_FILE_ID: u64 = 0x61A7;
+pub const FILE_DESCRIPTION_ID: u64 = 0x467E;
+pub const FILE_NAME_ID: u64 = 0x466E;
+pub const FILE_MEDIA_TYPE_ID: u64 = 0x4660;
+pub const FILE_DATA_ID: u64 = 0x465C;
+pub const FILE_UID_ID: u64 = 0x46AE;
+pub const CHAPTERS_ID: u64 = 0x1043_A770;
+pub const EDITION_ENTRY_ID: u64 = 0x45B9;
+pub const EDITION_UID_ID: u64 = 0x45BC;
+pub const EDITION_FLAG_HIDDEN_ID: u64 = 0x45BD;
+pub const EDITION_FLAG_DEFAULT_ID: u64 = 0x45DB;
+pub const EDITION_FLAG_ORDERED_ID: u64 = 0x45DD;
+pub const EDITION_DISPLAY_ID: u64 = 0x4520;
+pub const EDITION_STRING_ID: u64 = 0x4521;
+pub const EDITION_LANGUAGE_IETF_ID: u64 = 0x45E4;
+pub const CHAPTER_ATOM_ID: u64 = 0xB6;
+pub const CHAPTER_UID_ID: u64 = 0x73C4;
+pub const CHAPTER_STRING_UID_ID: u64 = 0x5654;
+pub const CHAPTER_TIME_START_ID: u64 = 0x91;
+pub const CHAPTER_TIME_END_ID: u64 = 0x92;
+pub const CHAPTER_FLAG_HIDDEN_ID: u64 = 0x98;
+pub const CHAPTER_FLAG_ENABLED_ID: u64 = 0x4598;
+pub const CHAPTER_SEGMENT_UUID_ID: u64 = 0x6E67;
+pub const CHAPTER_SKIP_TYPE_ID: u64 = 0x4588;
+pub const CHAPTER_SEGMENT_EDITION_UID_ID: u64 = 0x6EBC;
+pub const CHAPTER_PHYSICAL_EQUIV_ID: u64 = 0x63C3;
+pub const CHAPTER_TRACK_ID: u64 = 0x8F;
+pub const CHAPTER_TRACK_UID_ID: u64 = 0x89;
+pub const CHAPTER_DISPLAY_ID: u64 = 0x80;
+pub const CHAP_STRING_ID: u64 = 0x85;
+pub const CHAP_LANGUAGE_ID: u64 = 0x437C;
+pub const CHAP_LANGUAGE_BCP47_ID: u64 = 0x437D;
+pub const CHAP_COUNTRY_ID: u64 = 0x437E;
+pub const CHAP_PROCESS_ID: u64 = 0x6944;
+pub const CHAP_PROCESS_CODEC_ID_ID: u64 = 0x6955;
+pub const CHAP_PROCESS_PRIVATE_ID: u64 = 0x450D;
+pub const CHAP_PROCESS_COMMAND_ID: u64 = 0x6911;
+pub const CHAP_PROCESS_TIME_ID: u64 = 0x6922;
+pub const CHAP_PROCESS_DATA_ID: u64 = 0x6933;
+pub const TAGS_ID: u64 = 0x1254_C367;
+pub const TAG_ID: u64 = 0x7373;
+pub const TARGETS_ID: u64 = 0x63C0;
+pub const TARGET_TYPE_VALUE_ID: u64 = 0x68CA;
+pub const TARGET_TYPE_ID: u64 = 0x63CA;
+pub const TAG_TRACK_UID_ID: u64 = 0x63C5;
+pub const TAG_EDITION_UID_ID: u64 = 0x63C9;
+pub const TAG_CHAPTER_UID_ID: u64 = 0x63C4;
+pub const TAG_ATTACHMENT_UID_ID: u64 = 0x63C6;
+pub const SIMPLE_TAG_ID: u64 = 0x67C8;
+pub const TAG_NAME_ID: u64 = 0x45A3;
+pub const TAG_LANGUAGE_ID: u64 = 0x447A;
+pub const TAG_LANGUAGE_BCP47_ID: u64 = 0x447B;
+pub const TAG_DEFAULT_ID: u64 = 0x4484;
+pub const TAG_STRING_ID: u64 = 0x4487;
+pub const TAG_BINARY_ID: u64 = 0x4485;
+
+pub const MATROSKA_ELEMENTS: &[ElementDef] = &[
+    ElementDef { id: SEGMENT_ID, name: Cow::Borrowed("Segment"), path: Cow::Borrowed("\\Segment"), element_type: ElementType::Master, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: true },
+    ElementDef { id: SEEK_HEAD_ID, name: Cow::Borrowed("SeekHead"), path: Cow::Borrowed("\\Segment\\SeekHead"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(2), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: SEEK_ID, name: Cow::Borrowed("Seek"), path: Cow::Borrowed("\\Segment\\SeekHead\\Seek"), element_type: ElementType::Master, default: None, range: None, min_occurs: 1, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: SEEK_ID_ID, name: Cow::Borrowed("SeekID"), path: Cow::Borrowed("\\Segment\\SeekHead\\Seek\\SeekID"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: SEEK_POSITION_ID, name: Cow::Borrowed("SeekPosition"), path: Cow::Borrowed("\\Segment\\SeekHead\\Seek\\SeekPosition"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: INFO_ID, name: Cow::Borrowed("Info"), path: Cow::Borrowed("\\Segment\\Info"), element_type: ElementType::Master, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: SEGMENT_UUID_ID, name: Cow::Borrowed("SegmentUUID"), path: Cow::Borrowed("\\Segment\\Info\\SegmentUUID"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: SEGMENT_FILENAME_ID, name: Cow::Borrowed("SegmentFilename"), path: Cow::Borrowed("\\Segment\\Info\\SegmentFilename"), element_type: ElementType::Utf8, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PREV_UUID_ID, name: Cow::Borrowed("PrevUUID"), path: Cow::Borrowed("\\Segment\\Info\\PrevUUID"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PREV_FILENAME_ID, name: Cow::Borrowed("PrevFilename"), path: Cow::Borrowed("\\Segment\\Info\\PrevFilename"), element_type: ElementType::Utf8, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: NEXT_UUID_ID, name: Cow::Borrowed("NextUUID"), path: Cow::Borrowed("\\Segment\\Info\\NextUUID"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: NEXT_FILENAME_ID, name: Cow::Borrowed("NextFilename"), path: Cow::Borrowed("\\Segment\\Info\\NextFilename"), element_type: ElementType::Utf8, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: SEGMENT_FAMILY_ID, name: Cow::Borrowed("SegmentFamily"), path: Cow::Borrowed("\\Segment\\Info\\SegmentFamily"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_TRANSLATE_ID, name: Cow::Borrowed("ChapterTranslate"), path: Cow::Borrowed("\\Segment\\Info\\ChapterTranslate"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_TRANSLATE_ID_ID, name: Cow::Borrowed("ChapterTranslateID"), path: Cow::Borrowed("\\Segment\\Info\\ChapterTranslate\\ChapterTranslateID"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_TRANSLATE_CODEC_ID, name: Cow::Borrowed("ChapterTranslateCodec"), path: Cow::Borrowed("\\Segment\\Info\\ChapterTranslate\\ChapterTranslateCodec"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_TRANSLATE_EDITION_UID_ID, name: Cow::Borrowed("ChapterTranslateEditionUID"), path: Cow::Borrowed("\\Segment\\Info\\ChapterTranslate\\ChapterTranslateEditionUID"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TIMESTAMP_SCALE_ID, name: Cow::Borrowed("TimestampScale"), path: Cow::Borrowed("\\Segment\\Info\\TimestampScale"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("1000000")), range: Some(Cow::Borrowed("not 0")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: DURATION_ID, name: Cow::Borrowed("Duration"), path: Cow::Borrowed("\\Segment\\Info\\Duration"), element_type: ElementType::Float, default: None, range: Some(Cow::Borrowed("> 0x0p+0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: DATE_UTC_ID, name: Cow::Borrowed("DateUTC"), path: Cow::Borrowed("\\Segment\\Info\\DateUTC"), element_type: ElementType::Date, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TITLE_ID, name: Cow::Borrowed("Title"), path: Cow::Borrowed("\\Segment\\Info\\Title"), element_type: ElementType::Utf8, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: MUXING_APP_ID, name: Cow::Borrowed("MuxingApp"), path: Cow::Borrowed("\\Segment\\Info\\MuxingApp"), element_type: ElementType::Utf8, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: WRITING_APP_ID, name: Cow::Borrowed("WritingApp"), path: Cow::Borrowed("\\Segment\\Info\\WritingApp"), element_type: ElementType::Utf8, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CLUSTER_ID, name: Cow::Borrowed("Cluster"), path: Cow::Borrowed("\\Segment\\Cluster"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: true },
+    ElementDef { id: TIMESTAMP_ID, name: Cow::Borrowed("Timestamp"), path: Cow::Borrowed("\\Segment\\Cluster\\Timestamp"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: POSITION_ID, name: Cow::Borrowed("Position"), path: Cow::Borrowed("\\Segment\\Cluster\\Position"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PREV_SIZE_ID, name: Cow::Borrowed("PrevSize"), path: Cow::Borrowed("\\Segment\\Cluster\\PrevSize"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: SIMPLE_BLOCK_ID, name: Cow::Borrowed("SimpleBlock"), path: Cow::Borrowed("\\Segment\\Cluster\\SimpleBlock"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: BLOCK_GROUP_ID, name: Cow::Borrowed("BlockGroup"), path: Cow::Borrowed("\\Segment\\Cluster\\BlockGroup"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: BLOCK_ID, name: Cow::Borrowed("Block"), path: Cow::Borrowed("\\Segment\\Cluster\\BlockGroup\\Block"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: BLOCK_ADDITIONS_ID, name: Cow::Borrowed("BlockAdditions"), path: Cow::Borrowed("\\Segment\\Cluster\\BlockGroup\\BlockAdditions"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: BLOCK_MORE_ID, name: Cow::Borrowed("BlockMore"), path: Cow::Borrowed("\\Segment\\Cluster\\BlockGroup\\BlockAdditions\\BlockMore"), element_type: ElementType::Master, default: None, range: None, min_occurs: 1, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: BLOCK_ADDITIONAL_ID, name: Cow::Borrowed("BlockAdditional"), path: Cow::Borrowed("\\Segment\\Cluster\\BlockGroup\\BlockAdditions\\BlockMore\\BlockAdditional"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: BLOCK_ADD_ID_ID, name: Cow::Borrowed("BlockAddID"), path: Cow::Borrowed("\\Segment\\Cluster\\BlockGroup\\BlockAdditions\\BlockMore\\BlockAddID"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("1")), range: Some(Cow::Borrowed("not 0")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: BLOCK_DURATION_ID, name: Cow::Borrowed("BlockDuration"), path: Cow::Borrowed("\\Segment\\Cluster\\BlockGroup\\BlockDuration"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: REFERENCE_PRIORITY_ID, name: Cow::Borrowed("ReferencePriority"), path: Cow::Borrowed("\\Segment\\Cluster\\BlockGroup\\ReferencePriority"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: REFERENCE_BLOCK_ID, name: Cow::Borrowed("ReferenceBlock"), path: Cow::Borrowed("\\Segment\\Cluster\\BlockGroup\\ReferenceBlock"), element_type: ElementType::Integer, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CODEC_STATE_ID, name: Cow::Borrowed("CodecState"), path: Cow::Borrowed("\\Segment\\Cluster\\BlockGroup\\CodecState"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: DISCARD_PADDING_ID, name: Cow::Borrowed("DiscardPadding"), path: Cow::Borrowed("\\Segment\\Cluster\\BlockGroup\\DiscardPadding"), element_type: ElementType::Integer, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACKS_ID, name: Cow::Borrowed("Tracks"), path: Cow::Borrowed("\\Segment\\Tracks"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACK_ENTRY_ID, name: Cow::Borrowed("TrackEntry"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry"), element_type: ElementType::Master, default: None, range: None, min_occurs: 1, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACK_NUMBER_ID, name: Cow::Borrowed("TrackNumber"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\TrackNumber"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACK_UID_ID, name: Cow::Borrowed("TrackUID"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\TrackUID"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACK_TYPE_ID, name: Cow::Borrowed("TrackType"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\TrackType"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: FLAG_ENABLED_ID, name: Cow::Borrowed("FlagEnabled"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\FlagEnabled"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("1")), range: Some(Cow::Borrowed("0-1")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: FLAG_DEFAULT_ID, name: Cow::Borrowed("FlagDefault"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\FlagDefault"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("1")), range: Some(Cow::Borrowed("0-1")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: FLAG_FORCED_ID, name: Cow::Borrowed("FlagForced"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\FlagForced"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: Some(Cow::Borrowed("0-1")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: FLAG_HEARING_IMPAIRED_ID, name: Cow::Borrowed("FlagHearingImpaired"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\FlagHearingImpaired"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("0-1")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: FLAG_VISUAL_IMPAIRED_ID, name: Cow::Borrowed("FlagVisualImpaired"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\FlagVisualImpaired"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("0-1")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: FLAG_TEXT_DESCRIPTIONS_ID, name: Cow::Borrowed("FlagTextDescriptions"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\FlagTextDescriptions"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("0-1")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: FLAG_ORIGINAL_ID, name: Cow::Borrowed("FlagOriginal"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\FlagOriginal"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("0-1")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: FLAG_COMMENTARY_ID, name: Cow::Borrowed("FlagCommentary"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\FlagCommentary"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("0-1")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: FLAG_LACING_ID, name: Cow::Borrowed("FlagLacing"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\FlagLacing"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("1")), range: Some(Cow::Borrowed("0-1")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: DEFAULT_DURATION_ID, name: Cow::Borrowed("DefaultDuration"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\DefaultDuration"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: DEFAULT_DECODED_FIELD_DURATION_ID, name: Cow::Borrowed("DefaultDecodedFieldDuration"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\DefaultDecodedFieldDuration"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACK_TIMESTAMP_SCALE_ID, name: Cow::Borrowed("TrackTimestampScale"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\TrackTimestampScale"), element_type: ElementType::Float, default: Some(Cow::Borrowed("0x1p+0")), range: Some(Cow::Borrowed("> 0x0p+0")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: MAX_BLOCK_ADDITION_ID_ID, name: Cow::Borrowed("MaxBlockAdditionID"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\MaxBlockAdditionID"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: BLOCK_ADDITION_MAPPING_ID, name: Cow::Borrowed("BlockAdditionMapping"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\BlockAdditionMapping"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: BLOCK_ADD_ID_VALUE_ID, name: Cow::Borrowed("BlockAddIDValue"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\BlockAdditionMapping\\BlockAddIDValue"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed(">=2")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: BLOCK_ADD_ID_NAME_ID, name: Cow::Borrowed("BlockAddIDName"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\BlockAdditionMapping\\BlockAddIDName"), element_type: ElementType::String, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: BLOCK_ADD_ID_TYPE_ID, name: Cow::Borrowed("BlockAddIDType"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\BlockAdditionMapping\\BlockAddIDType"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: BLOCK_ADD_ID_EXTRA_DATA_ID, name: Cow::Borrowed("BlockAddIDExtraData"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\BlockAdditionMapping\\BlockAddIDExtraData"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: NAME_ID, name: Cow::Borrowed("Name"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Name"), element_type: ElementType::Utf8, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: LANGUAGE_ID, name: Cow::Borrowed("Language"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Language"), element_type: ElementType::String, default: Some(Cow::Borrowed("eng")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: LANGUAGE_BCP47_ID, name: Cow::Borrowed("LanguageBCP47"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\LanguageBCP47"), element_type: ElementType::String, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CODEC_ID_ID, name: Cow::Borrowed("CodecID"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\CodecID"), element_type: ElementType::String, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CODEC_PRIVATE_ID, name: Cow::Borrowed("CodecPrivate"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\CodecPrivate"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CODEC_NAME_ID, name: Cow::Borrowed("CodecName"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\CodecName"), element_type: ElementType::Utf8, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: ATTACHMENT_LINK_ID, name: Cow::Borrowed("AttachmentLink"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\AttachmentLink"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CODEC_DELAY_ID, name: Cow::Borrowed("CodecDelay"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\CodecDelay"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: SEEK_PRE_ROLL_ID, name: Cow::Borrowed("SeekPreRoll"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\SeekPreRoll"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACK_TRANSLATE_ID, name: Cow::Borrowed("TrackTranslate"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\TrackTranslate"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACK_TRANSLATE_TRACK_ID_ID, name: Cow::Borrowed("TrackTranslateTrackID"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\TrackTranslate\\TrackTranslateTrackID"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACK_TRANSLATE_CODEC_ID, name: Cow::Borrowed("TrackTranslateCodec"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\TrackTranslate\\TrackTranslateCodec"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACK_TRANSLATE_EDITION_UID_ID, name: Cow::Borrowed("TrackTranslateEditionUID"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\TrackTranslate\\TrackTranslateEditionUID"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: VIDEO_ID, name: Cow::Borrowed("Video"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: FLAG_INTERLACED_ID, name: Cow::Borrowed("FlagInterlaced"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\FlagInterlaced"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: FIELD_ORDER_ID, name: Cow::Borrowed("FieldOrder"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\FieldOrder"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("2")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: STEREO_MODE_ID, name: Cow::Borrowed("StereoMode"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\StereoMode"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: ALPHA_MODE_ID, name: Cow::Borrowed("AlphaMode"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\AlphaMode"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PIXEL_WIDTH_ID, name: Cow::Borrowed("PixelWidth"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\PixelWidth"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PIXEL_HEIGHT_ID, name: Cow::Borrowed("PixelHeight"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\PixelHeight"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PIXEL_CROP_BOTTOM_ID, name: Cow::Borrowed("PixelCropBottom"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\PixelCropBottom"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PIXEL_CROP_TOP_ID, name: Cow::Borrowed("PixelCropTop"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\PixelCropTop"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PIXEL_CROP_LEFT_ID, name: Cow::Borrowed("PixelCropLeft"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\PixelCropLeft"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PIXEL_CROP_RIGHT_ID, name: Cow::Borrowed("PixelCropRight"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\PixelCropRight"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: DISPLAY_WIDTH_ID, name: Cow::Borrowed("DisplayWidth"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\DisplayWidth"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: DISPLAY_HEIGHT_ID, name: Cow::Borrowed("DisplayHeight"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\DisplayHeight"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: DISPLAY_UNIT_ID, name: Cow::Borrowed("DisplayUnit"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\DisplayUnit"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: UNCOMPRESSED_FOUR_CC_ID, name: Cow::Borrowed("UncompressedFourCC"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\UncompressedFourCC"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: COLOUR_ID, name: Cow::Borrowed("Colour"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: MATRIX_COEFFICIENTS_ID, name: Cow::Borrowed("MatrixCoefficients"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MatrixCoefficients"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("2")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: BITS_PER_CHANNEL_ID, name: Cow::Borrowed("BitsPerChannel"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\BitsPerChannel"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHROMA_SUBSAMPLING_HORZ_ID, name: Cow::Borrowed("ChromaSubsamplingHorz"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\ChromaSubsamplingHorz"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHROMA_SUBSAMPLING_VERT_ID, name: Cow::Borrowed("ChromaSubsamplingVert"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\ChromaSubsamplingVert"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CB_SUBSAMPLING_HORZ_ID, name: Cow::Borrowed("CbSubsamplingHorz"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\CbSubsamplingHorz"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CB_SUBSAMPLING_VERT_ID, name: Cow::Borrowed("CbSubsamplingVert"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\CbSubsamplingVert"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHROMA_SITING_HORZ_ID, name: Cow::Borrowed("ChromaSitingHorz"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\ChromaSitingHorz"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHROMA_SITING_VERT_ID, name: Cow::Borrowed("ChromaSitingVert"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\ChromaSitingVert"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: RANGE_ID, name: Cow::Borrowed("Range"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\Range"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRANSFER_CHARACTERISTICS_ID, name: Cow::Borrowed("TransferCharacteristics"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\TransferCharacteristics"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("2")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PRIMARIES_ID, name: Cow::Borrowed("Primaries"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\Primaries"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("2")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: MAX_CLL_ID, name: Cow::Borrowed("MaxCLL"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MaxCLL"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: MAX_FALL_ID, name: Cow::Borrowed("MaxFALL"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MaxFALL"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: MASTERING_METADATA_ID, name: Cow::Borrowed("MasteringMetadata"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PRIMARY_R_CHROMATICITY_X_ID, name: Cow::Borrowed("PrimaryRChromaticityX"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\PrimaryRChromaticityX"), element_type: ElementType::Float, default: None, range: Some(Cow::Borrowed("0x0p+0-0x1p+0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PRIMARY_R_CHROMATICITY_Y_ID, name: Cow::Borrowed("PrimaryRChromaticityY"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\PrimaryRChromaticityY"), element_type: ElementType::Float, default: None, range: Some(Cow::Borrowed("0x0p+0-0x1p+0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PRIMARY_G_CHROMATICITY_X_ID, name: Cow::Borrowed("PrimaryGChromaticityX"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\PrimaryGChromaticityX"), element_type: ElementType::Float, default: None, range: Some(Cow::Borrowed("0x0p+0-0x1p+0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PRIMARY_G_CHROMATICITY_Y_ID, name: Cow::Borrowed("PrimaryGChromaticityY"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\PrimaryGChromaticityY"), element_type: ElementType::Float, default: None, range: Some(Cow::Borrowed("0x0p+0-0x1p+0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PRIMARY_B_CHROMATICITY_X_ID, name: Cow::Borrowed("PrimaryBChromaticityX"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\PrimaryBChromaticityX"), element_type: ElementType::Float, default: None, range: Some(Cow::Borrowed("0x0p+0-0x1p+0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PRIMARY_B_CHROMATICITY_Y_ID, name: Cow::Borrowed("PrimaryBChromaticityY"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\PrimaryBChromaticityY"), element_type: ElementType::Float, default: None, range: Some(Cow::Borrowed("0x0p+0-0x1p+0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: WHITE_POINT_CHROMATICITY_X_ID, name: Cow::Borrowed("WhitePointChromaticityX"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\WhitePointChromaticityX"), element_type: ElementType::Float, default: None, range: Some(Cow::Borrowed("0x0p+0-0x1p+0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: WHITE_POINT_CHROMATICITY_Y_ID, name: Cow::Borrowed("WhitePointChromaticityY"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\WhitePointChromaticityY"), element_type: ElementType::Float, default: None, range: Some(Cow::Borrowed("0x0p+0-0x1p+0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: LUMINANCE_MAX_ID, name: Cow::Borrowed("LuminanceMax"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\LuminanceMax"), element_type: ElementType::Float, default: None, range: Some(Cow::Borrowed(">= 0x0p+0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: LUMINANCE_MIN_ID, name: Cow::Borrowed("LuminanceMin"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\LuminanceMin"), element_type: ElementType::Float, default: None, range: Some(Cow::Borrowed(">= 0x0p+0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PROJECTION_ID, name: Cow::Borrowed("Projection"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Projection"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PROJECTION_TYPE_ID, name: Cow::Borrowed("ProjectionType"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Projection\\ProjectionType"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PROJECTION_PRIVATE_ID, name: Cow::Borrowed("ProjectionPrivate"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Projection\\ProjectionPrivate"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PROJECTION_POSE_YAW_ID, name: Cow::Borrowed("ProjectionPoseYaw"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Projection\\ProjectionPoseYaw"), element_type: ElementType::Float, default: Some(Cow::Borrowed("0x0p+0")), range: Some(Cow::Borrowed(">= -0xB4p+0, <= 0xB4p+0")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PROJECTION_POSE_PITCH_ID, name: Cow::Borrowed("ProjectionPosePitch"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Projection\\ProjectionPosePitch"), element_type: ElementType::Float, default: Some(Cow::Borrowed("0x0p+0")), range: Some(Cow::Borrowed(">= -0x5Ap+0, <= 0x5Ap+0")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: PROJECTION_POSE_ROLL_ID, name: Cow::Borrowed("ProjectionPoseRoll"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Video\\Projection\\ProjectionPoseRoll"), element_type: ElementType::Float, default: Some(Cow::Borrowed("0x0p+0")), range: Some(Cow::Borrowed(">= -0xB4p+0, <= 0xB4p+0")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: AUDIO_ID, name: Cow::Borrowed("Audio"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Audio"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: SAMPLING_FREQUENCY_ID, name: Cow::Borrowed("SamplingFrequency"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Audio\\SamplingFrequency"), element_type: ElementType::Float, default: Some(Cow::Borrowed("0x1.f4p+12")), range: Some(Cow::Borrowed("> 0x0p+0")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: OUTPUT_SAMPLING_FREQUENCY_ID, name: Cow::Borrowed("OutputSamplingFrequency"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Audio\\OutputSamplingFrequency"), element_type: ElementType::Float, default: None, range: Some(Cow::Borrowed("> 0x0p+0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHANNELS_ID, name: Cow::Borrowed("Channels"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Audio\\Channels"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("1")), range: Some(Cow::Borrowed("not 0")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: BIT_DEPTH_ID, name: Cow::Borrowed("BitDepth"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Audio\\BitDepth"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: EMPHASIS_ID, name: Cow::Borrowed("Emphasis"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\Audio\\Emphasis"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACK_OPERATION_ID, name: Cow::Borrowed("TrackOperation"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\TrackOperation"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACK_COMBINE_PLANES_ID, name: Cow::Borrowed("TrackCombinePlanes"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\TrackOperation\\TrackCombinePlanes"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACK_PLANE_ID, name: Cow::Borrowed("TrackPlane"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\TrackOperation\\TrackCombinePlanes\\TrackPlane"), element_type: ElementType::Master, default: None, range: None, min_occurs: 1, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACK_PLANE_UID_ID, name: Cow::Borrowed("TrackPlaneUID"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\TrackOperation\\TrackCombinePlanes\\TrackPlane\\TrackPlaneUID"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACK_PLANE_TYPE_ID, name: Cow::Borrowed("TrackPlaneType"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\TrackOperation\\TrackCombinePlanes\\TrackPlane\\TrackPlaneType"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACK_JOIN_BLOCKS_ID, name: Cow::Borrowed("TrackJoinBlocks"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\TrackOperation\\TrackJoinBlocks"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TRACK_JOIN_UID_ID, name: Cow::Borrowed("TrackJoinUID"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\TrackOperation\\TrackJoinBlocks\\TrackJoinUID"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 1, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CONTENT_ENCODINGS_ID, name: Cow::Borrowed("ContentEncodings"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\ContentEncodings"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CONTENT_ENCODING_ID, name: Cow::Borrowed("ContentEncoding"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding"), element_type: ElementType::Master, default: None, range: None, min_occurs: 1, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CONTENT_ENCODING_ORDER_ID, name: Cow::Borrowed("ContentEncodingOrder"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncodingOrder"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CONTENT_ENCODING_SCOPE_ID, name: Cow::Borrowed("ContentEncodingScope"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncodingScope"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("1")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CONTENT_ENCODING_TYPE_ID, name: Cow::Borrowed("ContentEncodingType"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncodingType"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CONTENT_COMPRESSION_ID, name: Cow::Borrowed("ContentCompression"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentCompression"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CONTENT_COMP_ALGO_ID, name: Cow::Borrowed("ContentCompAlgo"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentCompression\\ContentCompAlgo"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CONTENT_COMP_SETTINGS_ID, name: Cow::Borrowed("ContentCompSettings"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentCompression\\ContentCompSettings"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CONTENT_ENCRYPTION_ID, name: Cow::Borrowed("ContentEncryption"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncryption"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CONTENT_ENC_ALGO_ID, name: Cow::Borrowed("ContentEncAlgo"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncryption\\ContentEncAlgo"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CONTENT_ENC_KEY_ID_ID, name: Cow::Borrowed("ContentEncKeyID"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncryption\\ContentEncKeyID"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CONTENT_ENC_AES_SETTINGS_ID, name: Cow::Borrowed("ContentEncAESSettings"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncryption\\ContentEncAESSettings"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: AES_SETTINGS_CIPHER_MODE_ID, name: Cow::Borrowed("AESSettingsCipherMode"), path: Cow::Borrowed("\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncryption\\ContentEncAESSettings\\AESSettingsCipherMode"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CUES_ID, name: Cow::Borrowed("Cues"), path: Cow::Borrowed("\\Segment\\Cues"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CUE_POINT_ID, name: Cow::Borrowed("CuePoint"), path: Cow::Borrowed("\\Segment\\Cues\\CuePoint"), element_type: ElementType::Master, default: None, range: None, min_occurs: 1, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CUE_TIME_ID, name: Cow::Borrowed("CueTime"), path: Cow::Borrowed("\\Segment\\Cues\\CuePoint\\CueTime"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CUE_TRACK_POSITIONS_ID, name: Cow::Borrowed("CueTrackPositions"), path: Cow::Borrowed("\\Segment\\Cues\\CuePoint\\CueTrackPositions"), element_type: ElementType::Master, default: None, range: None, min_occurs: 1, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CUE_TRACK_ID, name: Cow::Borrowed("CueTrack"), path: Cow::Borrowed("\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueTrack"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CUE_CLUSTER_POSITION_ID, name: Cow::Borrowed("CueClusterPosition"), path: Cow::Borrowed("\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueClusterPosition"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CUE_RELATIVE_POSITION_ID, name: Cow::Borrowed("CueRelativePosition"), path: Cow::Borrowed("\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueRelativePosition"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CUE_DURATION_ID, name: Cow::Borrowed("CueDuration"), path: Cow::Borrowed("\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueDuration"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CUE_BLOCK_NUMBER_ID, name: Cow::Borrowed("CueBlockNumber"), path: Cow::Borrowed("\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueBlockNumber"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CUE_CODEC_STATE_ID, name: Cow::Borrowed("CueCodecState"), path: Cow::Borrowed("\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueCodecState"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CUE_REFERENCE_ID, name: Cow::Borrowed("CueReference"), path: Cow::Borrowed("\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueReference"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CUE_REF_TIME_ID, name: Cow::Borrowed("CueRefTime"), path: Cow::Borrowed("\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueReference\\CueRefTime"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: ATTACHMENTS_ID, name: Cow::Borrowed("Attachments"), path: Cow::Borrowed("\\Segment\\Attachments"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: ATTACHED_FILE_ID, name: Cow::Borrowed("AttachedFile"), path: Cow::Borrowed("\\Segment\\Attachments\\AttachedFile"), element_type: ElementType::Master, default: None, range: None, min_occurs: 1, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: FILE_DESCRIPTION_ID, name: Cow::Borrowed("FileDescription"), path: Cow::Borrowed("\\Segment\\Attachments\\AttachedFile\\FileDescription"), element_type: ElementType::Utf8, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: FILE_NAME_ID, name: Cow::Borrowed("FileName"), path: Cow::Borrowed("\\Segment\\Attachments\\AttachedFile\\FileName"), element_type: ElementType::Utf8, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: FILE_MEDIA_TYPE_ID, name: Cow::Borrowed("FileMediaType"), path: Cow::Borrowed("\\Segment\\Attachments\\AttachedFile\\FileMediaType"), element_type: ElementType::String, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: FILE_DATA_ID, name: Cow::Borrowed("FileData"), path: Cow::Borrowed("\\Segment\\Attachments\\AttachedFile\\FileData"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: FILE_UID_ID, name: Cow::Borrowed("FileUID"), path: Cow::Borrowed("\\Segment\\Attachments\\AttachedFile\\FileUID"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTERS_ID, name: Cow::Borrowed("Chapters"), path: Cow::Borrowed("\\Segment\\Chapters"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: EDITION_ENTRY_ID, name: Cow::Borrowed("EditionEntry"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry"), element_type: ElementType::Master, default: None, range: None, min_occurs: 1, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: EDITION_UID_ID, name: Cow::Borrowed("EditionUID"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\EditionUID"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: EDITION_FLAG_HIDDEN_ID, name: Cow::Borrowed("EditionFlagHidden"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\EditionFlagHidden"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: Some(Cow::Borrowed("0-1")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: EDITION_FLAG_DEFAULT_ID, name: Cow::Borrowed("EditionFlagDefault"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\EditionFlagDefault"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: Some(Cow::Borrowed("0-1")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: EDITION_FLAG_ORDERED_ID, name: Cow::Borrowed("EditionFlagOrdered"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\EditionFlagOrdered"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: Some(Cow::Borrowed("0-1")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: EDITION_DISPLAY_ID, name: Cow::Borrowed("EditionDisplay"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\EditionDisplay"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: EDITION_STRING_ID, name: Cow::Borrowed("EditionString"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\EditionDisplay\\EditionString"), element_type: ElementType::Utf8, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: EDITION_LANGUAGE_IETF_ID, name: Cow::Borrowed("EditionLanguageIETF"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\EditionDisplay\\EditionLanguageIETF"), element_type: ElementType::String, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_ATOM_ID, name: Cow::Borrowed("ChapterAtom"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom"), element_type: ElementType::Master, default: None, range: None, min_occurs: 1, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_UID_ID, name: Cow::Borrowed("ChapterUID"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterUID"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_STRING_UID_ID, name: Cow::Borrowed("ChapterStringUID"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterStringUID"), element_type: ElementType::Utf8, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_TIME_START_ID, name: Cow::Borrowed("ChapterTimeStart"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterTimeStart"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_TIME_END_ID, name: Cow::Borrowed("ChapterTimeEnd"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterTimeEnd"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_FLAG_HIDDEN_ID, name: Cow::Borrowed("ChapterFlagHidden"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterFlagHidden"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: Some(Cow::Borrowed("0-1")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_FLAG_ENABLED_ID, name: Cow::Borrowed("ChapterFlagEnabled"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterFlagEnabled"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("1")), range: Some(Cow::Borrowed("0-1")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_SEGMENT_UUID_ID, name: Cow::Borrowed("ChapterSegmentUUID"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterSegmentUUID"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_SKIP_TYPE_ID, name: Cow::Borrowed("ChapterSkipType"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterSkipType"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_SEGMENT_EDITION_UID_ID, name: Cow::Borrowed("ChapterSegmentEditionUID"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterSegmentEditionUID"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_PHYSICAL_EQUIV_ID, name: Cow::Borrowed("ChapterPhysicalEquiv"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterPhysicalEquiv"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_TRACK_ID, name: Cow::Borrowed("ChapterTrack"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterTrack"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_TRACK_UID_ID, name: Cow::Borrowed("ChapterTrackUID"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterTrack\\ChapterTrackUID"), element_type: ElementType::UnsignedInteger, default: None, range: Some(Cow::Borrowed("not 0")), min_occurs: 1, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAPTER_DISPLAY_ID, name: Cow::Borrowed("ChapterDisplay"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterDisplay"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAP_STRING_ID, name: Cow::Borrowed("ChapString"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterDisplay\\ChapString"), element_type: ElementType::Utf8, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAP_LANGUAGE_ID, name: Cow::Borrowed("ChapLanguage"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterDisplay\\ChapLanguage"), element_type: ElementType::String, default: Some(Cow::Borrowed("eng")), range: None, min_occurs: 1, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAP_LANGUAGE_BCP47_ID, name: Cow::Borrowed("ChapLanguageBCP47"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterDisplay\\ChapLanguageBCP47"), element_type: ElementType::String, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAP_COUNTRY_ID, name: Cow::Borrowed("ChapCountry"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterDisplay\\ChapCountry"), element_type: ElementType::String, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAP_PROCESS_ID, name: Cow::Borrowed("ChapProcess"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapProcess"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAP_PROCESS_CODEC_ID_ID, name: Cow::Borrowed("ChapProcessCodecID"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapProcess\\ChapProcessCodecID"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAP_PROCESS_PRIVATE_ID, name: Cow::Borrowed("ChapProcessPrivate"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapProcess\\ChapProcessPrivate"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAP_PROCESS_COMMAND_ID, name: Cow::Borrowed("ChapProcessCommand"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapProcess\\ChapProcessCommand"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAP_PROCESS_TIME_ID, name: Cow::Borrowed("ChapProcessTime"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapProcess\\ChapProcessCommand\\ChapProcessTime"), element_type: ElementType::UnsignedInteger, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: CHAP_PROCESS_DATA_ID, name: Cow::Borrowed("ChapProcessData"), path: Cow::Borrowed("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapProcess\\ChapProcessCommand\\ChapProcessData"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TAGS_ID, name: Cow::Borrowed("Tags"), path: Cow::Borrowed("\\Segment\\Tags"), element_type: ElementType::Master, default: None, range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TAG_ID, name: Cow::Borrowed("Tag"), path: Cow::Borrowed("\\Segment\\Tags\\Tag"), element_type: ElementType::Master, default: None, range: None, min_occurs: 1, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TARGETS_ID, name: Cow::Borrowed("Targets"), path: Cow::Borrowed("\\Segment\\Tags\\Tag\\Targets"), element_type: ElementType::Master, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TARGET_TYPE_VALUE_ID, name: Cow::Borrowed("TargetTypeValue"), path: Cow::Borrowed("\\Segment\\Tags\\Tag\\Targets\\TargetTypeValue"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("50")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TARGET_TYPE_ID, name: Cow::Borrowed("TargetType"), path: Cow::Borrowed("\\Segment\\Tags\\Tag\\Targets\\TargetType"), element_type: ElementType::String, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TAG_TRACK_UID_ID, name: Cow::Borrowed("TagTrackUID"), path: Cow::Borrowed("\\Segment\\Tags\\Tag\\Targets\\TagTrackUID"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TAG_EDITION_UID_ID, name: Cow::Borrowed("TagEditionUID"), path: Cow::Borrowed("\\Segment\\Tags\\Tag\\Targets\\TagEditionUID"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TAG_CHAPTER_UID_ID, name: Cow::Borrowed("TagChapterUID"), path: Cow::Borrowed("\\Segment\\Tags\\Tag\\Targets\\TagChapterUID"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TAG_ATTACHMENT_UID_ID, name: Cow::Borrowed("TagAttachmentUID"), path: Cow::Borrowed("\\Segment\\Tags\\Tag\\Targets\\TagAttachmentUID"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("0")), range: None, min_occurs: 0, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: SIMPLE_TAG_ID, name: Cow::Borrowed("SimpleTag"), path: Cow::Borrowed("\\Segment\\Tags\\Tag\\+SimpleTag"), element_type: ElementType::Master, default: None, range: None, min_occurs: 1, max_occurs: None, recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TAG_NAME_ID, name: Cow::Borrowed("TagName"), path: Cow::Borrowed("\\Segment\\Tags\\Tag\\+SimpleTag\\TagName"), element_type: ElementType::Utf8, default: None, range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TAG_LANGUAGE_ID, name: Cow::Borrowed("TagLanguage"), path: Cow::Borrowed("\\Segment\\Tags\\Tag\\+SimpleTag\\TagLanguage"), element_type: ElementType::String, default: Some(Cow::Borrowed("und")), range: None, min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TAG_LANGUAGE_BCP47_ID, name: Cow::Borrowed("TagLanguageBCP47"), path: Cow::Borrowed("\\Segment\\Tags\\Tag\\+SimpleTag\\TagLanguageBCP47"), element_type: ElementType::String, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TAG_DEFAULT_ID, name: Cow::Borrowed("TagDefault"), path: Cow::Borrowed("\\Segment\\Tags\\Tag\\+SimpleTag\\TagDefault"), element_type: ElementType::UnsignedInteger, default: Some(Cow::Borrowed("1")), range: Some(Cow::Borrowed("0-1")), min_occurs: 1, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TAG_STRING_ID, name: Cow::Borrowed("TagString"), path: Cow::Borrowed("\\Segment\\Tags\\Tag\\+SimpleTag\\TagString"), element_type: ElementType::Utf8, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+    ElementDef { id: TAG_BINARY_ID, name: Cow::Borrowed("TagBinary"), path: Cow::Borrowed("\\Segment\\Tags\\Tag\\+SimpleTag\\TagBinary"), element_type: ElementType::Binary, default: None, range: None, min_occurs: 0, max_occurs: Some(1), recurring: false, unknown_size_allowed: false },
+];
+
+// Returns the definition of the Matroska element `id`
+#[allow(clippy::too_many_lines)]
+pub fn matroska_element(id: u64) -> Option<&'static ElementDef> {
+    let index = match id {
+        SEGMENT_ID => 0,
+        SEEK_HEAD_ID => 1,
+        SEEK_ID => 2,
+        SEEK_ID_ID => 3,
+        SEEK_POSITION_ID => 4,
+        INFO_ID => 5,
+        SEGMENT_UUID_ID => 6,
+        SEGMENT_FILENAME_ID => 7,
+        PREV_UUID_ID => 8,
+        PREV_FILENAME_ID => 9,
+        NEXT_UUID_ID => 10,
+        NEXT_FILENAME_ID => 11,
+        SEGMENT_FAMILY_ID => 12,
+        CHAPTER_TRANSLATE_ID => 13,
+        CHAPTER_TRANSLATE_ID_ID => 14,
+        CHAPTER_TRANSLATE_CODEC_ID => 15,
+        CHAPTER_TRANSLATE_EDITION_UID_ID => 16,
+        TIMESTAMP_SCALE_ID => 17,
+        DURATION_ID => 18,
+        DATE_UTC_ID => 19,
+        TITLE_ID => 20,
+        MUXING_APP_ID => 21,
+        WRITING_APP_ID => 22,
+        CLUSTER_ID => 23,
+        TIMESTAMP_ID => 24,
+        POSITION_ID => 25,
+        PREV_SIZE_ID => 26,
+        SIMPLE_BLOCK_ID => 27,
+        BLOCK_GROUP_ID => 28,
+        BLOCK_ID => 29,
+        BLOCK_ADDITIONS_ID => 30,
+        BLOCK_MORE_ID => 31,
+        BLOCK_ADDITIONAL_ID => 32,
+        BLOCK_ADD_ID_ID => 33,
+        BLOCK_DURATION_ID => 34,
+        REFERENCE_PRIORITY_ID => 35,
+        REFERENCE_BLOCK_ID => 36,
+        CODEC_STATE_ID => 37,
+        DISCARD_PADDING_ID => 38,
+        TRACKS_ID => 39,
+        TRACK_ENTRY_ID => 40,
+        TRACK_NUMBER_ID => 41,
+        TRACK_UID_ID => 42,
+        TRACK_TYPE_ID => 43,
+        FLAG_ENABLED_ID => 44,
+        FLAG_DEFAULT_ID => 45,
+        FLAG_FORCED_ID => 46,
+        FLAG_HEARING_IMPAIRED_ID => 47,
+        FLAG_VISUAL_IMPAIRED_ID => 48,
+        FLAG_TEXT_DESCRIPTIONS_ID => 49,
+        FLAG_ORIGINAL_ID => 50,
+        FLAG_COMMENTARY_ID => 51,
+        FLAG_LACING_ID => 52,
+        DEFAULT_DURATION_ID => 53,
+        DEFAULT_DECODED_FIELD_DURATION_ID => 54,
+        TRACK_TIMESTAMP_SCALE_ID => 55,
+        MAX_BLOCK_ADDITION_ID_ID => 56,
+        BLOCK_ADDITION_MAPPING_ID => 57,
+        BLOCK_ADD_ID_VALUE_ID => 58,
+        BLOCK_ADD_ID_NAME_ID => 59,
+        BLOCK_ADD_ID_TYPE_ID => 60,
+        BLOCK_ADD_ID_EXTRA_DATA_ID => 61,
+        NAME_ID => 62,
+        LANGUAGE_ID => 63,
+        LANGUAGE_BCP47_ID => 64,
+        CODEC_ID_ID => 65,
+        CODEC_PRIVATE_ID => 66,
+        CODEC_NAME_ID => 67,
+        ATTACHMENT_LINK_ID => 68,
+        CODEC_DELAY_ID => 69,
+        SEEK_PRE_ROLL_ID => 70,
+        TRACK_TRANSLATE_ID => 71,
+        TRACK_TRANSLATE_TRACK_ID_ID => 72,
+        TRACK_TRANSLATE_CODEC_ID => 73,
+        TRACK_TRANSLATE_EDITION_UID_ID => 74,
+        VIDEO_ID => 75,
+        FLAG_INTERLACED_ID => 76,
+        FIELD_ORDER_ID => 77,
+        STEREO_MODE_ID => 78,
+        ALPHA_MODE_ID => 79,
+        PIXEL_WIDTH_ID => 80,
+        PIXEL_HEIGHT_ID => 81,
+        PIXEL_CROP_BOTTOM_ID => 82,
+        PIXEL_CROP_TOP_ID => 83,
+        PIXEL_CROP_LEFT_ID => 84,
+        PIXEL_CROP_RIGHT_ID => 85,
+        DISPLAY_WIDTH_ID => 86,
+        DISPLAY_HEIGHT_ID => 87,
+        DISPLAY_UNIT_ID => 88,
+        UNCOMPRESSED_FOUR_CC_ID => 89,
+        COLOUR_ID => 90,
+        MATRIX_COEFFICIENTS_ID => 91,
+        BITS_PER_CHANNEL_ID => 92,
+        CHROMA_SUBSAMPLING_HORZ_ID => 93,
+        CHROMA_SUBSAMPLING_VERT_ID => 94,
+        CB_SUBSAMPLING_HORZ_ID => 95,
+        CB_SUBSAMPLING_VERT_ID => 96,
+        CHROMA_SITING_HORZ_ID => 97,
+        CHROMA_SITING_VERT_ID => 98,
+        RANGE_ID => 99,
+        TRANSFER_CHARACTERISTICS_ID => 100,
+        PRIMARIES_ID => 101,
+        MAX_CLL_ID => 102,
+        MAX_FALL_ID => 103,
+        MASTERING_METADATA_ID => 104,
+        PRIMARY_R_CHROMATICITY_X_ID => 105,
+        PRIMARY_R_CHROMATICITY_Y_ID => 106,
+        PRIMARY_G_CHROMATICITY_X_ID => 107,
+        PRIMARY_G_CHROMATICITY_Y_ID => 108,
+        PRIMARY_B_CHROMATICITY_X_ID => 109,
+        PRIMARY_B_CHROMATICITY_Y_ID => 110,
+        WHITE_POINT_CHROMATICITY_X_ID => 111,
+        WHITE_POINT_CHROMATICITY_Y_ID => 112,
+        LUMINANCE_MAX_ID => 113,
+        LUMINANCE_MIN_ID => 114,
+        PROJECTION_ID => 115,
+        PROJECTION_TYPE_ID => 116,
+        PROJECTION_PRIVATE_ID => 117,
+        PROJECTION_POSE_YAW_ID => 118,
+        PROJECTION_POSE_PITCH_ID => 119,
+        PROJECTION_POSE_ROLL_ID => 120,
+        AUDIO_ID => 121,
+        SAMPLING_FREQUENCY_ID => 122,
+        OUTPUT_SAMPLING_FREQUENCY_ID => 123,
+        CHANNELS_ID => 124,
+        BIT_DEPTH_ID => 125,
+        EMPHASIS_ID => 126,
+        TRACK_OPERATION_ID => 127,
+        TRACK_COMBINE_PLANES_ID => 128,
+        TRACK_PLANE_ID => 129,
+        TRACK_PLANE_UID_ID => 130,
+        TRACK_PLANE_TYPE_ID => 131,
+        TRACK_JOIN_BLOCKS_ID => 132,
+        TRACK_JOIN_UID_ID => 133,
+        CONTENT_ENCODINGS_ID => 134,
+        CONTENT_ENCODING_ID => 135,
+        CONTENT_ENCODING_ORDER_ID => 136,
+        CONTENT_ENCODING_SCOPE_ID => 137,
+        CONTENT_ENCODING_TYPE_ID => 138,
+        CONTENT_COMPRESSION_ID => 139,
+        CONTENT_COMP_ALGO_ID => 140,
+        CONTENT_COMP_SETTINGS_ID => 141,
+        CONTENT_ENCRYPTION_ID => 142,
+        CONTENT_ENC_ALGO_ID => 143,
+        CONTENT_ENC_KEY_ID_ID => 144,
+        CONTENT_ENC_AES_SETTINGS_ID => 145,
+        AES_SETTINGS_CIPHER_MODE_ID => 146,
+        CUES_ID => 147,
+        CUE_POINT_ID => 148,
+        CUE_TIME_ID => 149,
+        CUE_TRACK_POSITIONS_ID => 150,
+        CUE_TRACK_ID => 151,
+        CUE_CLUSTER_POSITION_ID => 152,
+        CUE_RELATIVE_POSITION_ID => 153,
+        CUE_DURATION_ID => 154,
+        CUE_BLOCK_NUMBER_ID => 155,
+        CUE_CODEC_STATE_ID => 156,
+        CUE_REFERENCE_ID => 157,
+        CUE_REF_TIME_ID => 158,
+        ATTACHMENTS_ID => 159,
+        ATTACHED_FILE_ID => 160,
+        FILE_DESCRIPTION_ID => 161,
+        FILE_NAME_ID => 162,
+        FILE_MEDIA_TYPE_ID => 163,
+        FILE_DATA_ID => 164,
+        FILE_UID_ID => 165,
+        CHAPTERS_ID => 166,
+        EDITION_ENTRY_ID => 167,
+        EDITION_UID_ID => 168,
+        EDITION_FLAG_HIDDEN_ID => 169,
+        EDITION_FLAG_DEFAULT_ID => 170,
+        EDITION_FLAG_ORDERED_ID => 171,
+        EDITION_DISPLAY_ID => 172,
+        EDITION_STRING_ID => 173,
+        EDITION_LANGUAGE_IETF_ID => 174,
+        CHAPTER_ATOM_ID => 175,
+        CHAPTER_UID_ID => 176,
+        CHAPTER_STRING_UID_ID => 177,
+        CHAPTER_TIME_START_ID => 178,
+        CHAPTER_TIME_END_ID => 179,
+        CHAPTER_FLAG_HIDDEN_ID => 180,
+        CHAPTER_FLAG_ENABLED_ID => 181,
+        CHAPTER_SEGMENT_UUID_ID => 182,
+        CHAPTER_SKIP_TYPE_ID => 183,
+        CHAPTER_SEGMENT_EDITION_UID_ID => 184,
+        CHAPTER_PHYSICAL_EQUIV_ID => 185,
+        CHAPTER_TRACK_ID => 186,
+        CHAPTER_TRACK_UID_ID => 187,
+        CHAPTER_DISPLAY_ID => 188,
+        CHAP_STRING_ID => 189,
+        CHAP_LANGUAGE_ID => 190,
+        CHAP_LANGUAGE_BCP47_ID => 191,
+        CHAP_COUNTRY_ID => 192,
+        CHAP_PROCESS_ID => 193,
+        CHAP_PROCESS_CODEC_ID_ID => 194,
+        CHAP_PROCESS_PRIVATE_ID => 195,
+        CHAP_PROCESS_COMMAND_ID => 196,
+        CHAP_PROCESS_TIME_ID => 197,
+        CHAP_PROCESS_DATA_ID => 198,
+        TAGS_ID => 199,
+        TAG_ID => 200,
+        TARGETS_ID => 201,
+        TARGET_TYPE_VALUE_ID => 202,
+        TARGET_TYPE_ID => 203,
+        TAG_TRACK_UID_ID => 204,
+        TAG_EDITION_UID_ID => 205,
+        TAG_CHAPTER_UID_ID => 206,
+        TAG_ATTACHMENT_UID_ID => 207,
+        SIMPLE_TAG_ID => 208,
+        TAG_NAME_ID => 209,
+        TAG_LANGUAGE_ID => 210,
+        TAG_LANGUAGE_BCP47_ID => 211,
+        TAG_DEFAULT_ID => 212,
+        TAG_STRING_ID => 213,
+        TAG_BINARY_ID => 214,
+        _ => return None,
+    };
+    Some(&MATROSKA_ELEMENTS[index])
+}
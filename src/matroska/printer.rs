@@ -1,11 +1,14 @@
 use std::fmt;
 use std::fmt::Write;
 
+use crate::ebml::primitives::{EbmlDate, HexBytes};
 use crate::ebml::reader::ParsedElement;
-use crate::matroska::{EbmlHeader, Field, Info, MatroskaDocument, OptionalField, Segment};
+use crate::matroska::{
+    EbmlHeader, Field, MatroskaDocument, OptionalField, Segment, ValueSource, element_name,
+};
 use crate::util::tree_printer::{TreePrintable, TreePrinter};
 
-fn element_label(name: &str, raw: &ParsedElement, show_bytes: bool) -> String {
+pub(crate) fn element_label(name: &str, raw: &ParsedElement, show_bytes: bool) -> String {
     let mut label = name.to_string();
     if show_bytes {
         write!(
@@ -19,8 +22,53 @@ fn element_label(name: &str, raw: &ParsedElement, show_bytes: bool) -> String {
     label
 }
 
-fn field_label<T: fmt::Debug>(name: &str, field: &Field<T>, show_bytes: bool) -> String {
-    let mut label = format!("{name}: {:?}", field.value);
+// Field values are printed like the values of the generic EBML tree printer,
+// except floats, which keep their fractional part, e.g. `1500.0`
+pub(crate) trait PrintValue {
+    fn print_value(&self) -> String;
+}
+
+impl PrintValue for u64 {
+    fn print_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl PrintValue for i64 {
+    fn print_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl PrintValue for f64 {
+    fn print_value(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl PrintValue for String {
+    fn print_value(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+impl PrintValue for EbmlDate {
+    fn print_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl PrintValue for Vec<u8> {
+    fn print_value(&self) -> String {
+        HexBytes(self).to_string()
+    }
+}
+
+fn field_label<T: PrintValue>(name: &str, field: &Field<T>, show_bytes: bool) -> String {
+    let mut label = format!("{name}: {}", field.value.print_value());
+    if field.source == ValueSource::EmptyDefault {
+        label.push_str(" (empty, default)");
+    }
     if show_bytes {
         write!(
             label,
@@ -33,14 +81,20 @@ fn field_label<T: fmt::Debug>(name: &str, field: &Field<T>, show_bytes: bool) ->
     label
 }
 
-fn optional_field_label<T: fmt::Debug>(
-    name: &str,
-    field: &OptionalField<T>,
-    show_bytes: bool,
-) -> Option<String> {
-    match field {
-        OptionalField::Present(f) => Some(field_label(name, f, show_bytes)),
-        OptionalField::Default(_) => None,
+// Fields of derived elements are labeled with their schema name
+impl<T: PrintValue> TreePrintable for Field<T> {
+    fn print_tree(
+        &self,
+        out: &mut String,
+        printer: &mut TreePrinter,
+        last: bool,
+        show_bytes: bool,
+    ) -> fmt::Result {
+        printer.node(
+            out,
+            last,
+            field_label(element_name(self.raw.id), self, show_bytes),
+        )
     }
 }
 
@@ -57,23 +111,31 @@ impl TreePrintable for EbmlHeader {
             last,
             element_label("EBML Header", &self.raw, show_bytes),
         )?;
-        printer.child_scope(last, |printer| {
-            let labels = vec![
-                Some(field_label("docType", &self.doctype, show_bytes)),
-                optional_field_label("docTypeVersion", &self.doctype_version, show_bytes),
-                optional_field_label("docTypeReadVersion", &self.doctype_read_version, show_bytes),
-                optional_field_label("maxIDLength", &self.max_id_length, show_bytes),
-                optional_field_label("maxSizeLength", &self.max_size_length, show_bytes),
-            ]
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>();
-
-            for (i, label) in labels.iter().enumerate() {
-                let is_last = i == labels.len() - 1;
-                printer.node(out, is_last, label)?;
+        let mut children: Vec<&dyn TreePrintable> = Vec::new();
+        for field in [&self.ebml_version, &self.ebml_read_version] {
+            if let OptionalField::Present(field) = field {
+                children.push(field);
             }
+        }
+        children.push(&self.doctype);
+        for field in [&self.doctype_version, &self.doctype_read_version] {
+            if let OptionalField::Present(field) = field {
+                children.push(field);
+            }
+        }
+        for extension in &self.doctype_extensions {
+            children.push(extension);
+        }
+        for field in [&self.max_id_length, &self.max_size_length] {
+            if let OptionalField::Present(field) = field {
+                children.push(field);
+            }
+        }
 
+        printer.child_scope(last, |printer| {
+            for (i, child) in children.iter().enumerate() {
+                child.print_tree(out, printer, i == children.len() - 1, show_bytes)?;
+            }
             Ok(())
         })
     }
@@ -89,26 +151,17 @@ impl TreePrintable for Segment {
     ) -> fmt::Result {
         printer.node(out, last, element_label("Segment", &self.raw, show_bytes))?;
         printer.child_scope(last, |printer| {
-            self.info.print_tree(out, printer, true, show_bytes)?;
+            self.info
+                .print_tree(out, printer, self.tracks.is_none(), show_bytes)?;
+            if let Some(tracks) = &self.tracks {
+                tracks.print_tree(out, printer, true, show_bytes)?;
+            }
             Ok(())
         })?;
         Ok(())
     }
 }
 
-impl TreePrintable for Info {
-    fn print_tree(
-        &self,
-        out: &mut String,
-        printer: &mut TreePrinter,
-        last: bool,
-        show_bytes: bool,
-    ) -> fmt::Result {
-        printer.node(out, last, element_label("Info", &self.raw, show_bytes))?;
-        Ok(())
-    }
-}
-
 pub fn print_matroska_tree(doc: &MatroskaDocument, show_bytes: bool) -> Result<String, fmt::Error> {
     let mut out = String::new();
     let mut printer = TreePrinter::new();
@@ -163,12 +216,197 @@ mod tests {
         assert_eq!(
             "MatroskaDocument
 ├── EBML Header
-│   ├── docType: \"matroska\"
-│   └── maxIDLength: 4
+│   ├── DocType: \"matroska\"
+│   └── EBMLMaxIDLength: 4
 └── Segment
     └── Info
 ",
             tree_string
         );
     }
+
+    fn print_bytes(ebml_tree: &Element) -> String {
+        let bytes = ebml_tree.to_bytes().unwrap();
+        let matroska_doc = MatroskaDocument::parse_from(std::io::Cursor::new(bytes)).unwrap();
+        print_matroska_tree(&matroska_doc, false).unwrap()
+    }
+
+    #[test]
+    fn test_print_empty_default() {
+        let ebml_tree = Element::Root {
+            children: vec![
+                Element::Master {
+                    id: matroska::EBML_HEADER_ID,
+                    children: vec![
+                        Element::Raw {
+                            id: matroska::EBML_HEADER_DOCTYPE_ID,
+                            data: b"matroska".to_vec(),
+                        },
+                        Element::Raw {
+                            id: matroska::EBML_HEADER_DOCTYPE_VERSION_ID,
+                            data: vec![],
+                        },
+                    ],
+                },
+                Element::Master {
+                    id: matroska::SEGMENT_ID,
+                    children: vec![Element::Master {
+                        id: matroska::INFO_ID,
+                        children: vec![],
+                    }],
+                },
+            ],
+        };
+
+        assert_eq!(
+            "MatroskaDocument
+├── EBML Header
+│   ├── DocType: \"matroska\"
+│   └── DocTypeVersion: 1 (empty, default)
+└── Segment
+    └── Info
+",
+            print_bytes(&ebml_tree)
+        );
+    }
+
+    #[test]
+    fn test_print_ebml_header() {
+        let ebml_tree = Element::Root {
+            children: vec![
+                Element::Master {
+                    id: matroska::EBML_HEADER_ID,
+                    children: vec![
+                        Element::Raw {
+                            id: matroska::EBML_HEADER_VERSION_ID,
+                            data: vec![0x01],
+                        },
+                        Element::Raw {
+                            id: matroska::EBML_HEADER_DOCTYPE_ID,
+                            data: b"matroska".to_vec(),
+                        },
+                        Element::Master {
+                            id: matroska::EBML_HEADER_DOCTYPE_EXTENSION_ID,
+                            children: vec![
+                                Element::Raw {
+                                    id: matroska::EBML_HEADER_DOCTYPE_EXTENSION_NAME_ID,
+                                    data: b"mkvedit".to_vec(),
+                                },
+                                Element::Raw {
+                                    id: matroska::EBML_HEADER_DOCTYPE_EXTENSION_VERSION_ID,
+                                    data: vec![0x02],
+                                },
+                            ],
+                        },
+                        Element::Raw {
+                            id: matroska::EBML_HEADER_MAX_SIZE_LENGTH_ID,
+                            data: vec![0x08],
+                        },
+                    ],
+                },
+                Element::Master {
+                    id: matroska::SEGMENT_ID,
+                    children: vec![Element::Master {
+                        id: matroska::INFO_ID,
+                        children: vec![],
+                    }],
+                },
+            ],
+        };
+
+        assert_eq!(
+            "MatroskaDocument
+├── EBML Header
+│   ├── EBMLVersion: 1
+│   ├── DocType: \"matroska\"
+│   ├── DocTypeExtension
+│   │   ├── DocTypeExtensionName: \"mkvedit\"
+│   │   └── DocTypeExtensionVersion: 2
+│   └── EBMLMaxSizeLength: 8
+└── Segment
+    └── Info
+",
+            print_bytes(&ebml_tree)
+        );
+    }
+
+    #[test]
+    fn test_print_info_and_tracks() {
+        let ebml_tree = Element::Root {
+            children: vec![
+                Element::Master {
+                    id: matroska::EBML_HEADER_ID,
+                    children: vec![Element::Raw {
+                        id: matroska::EBML_HEADER_DOCTYPE_ID,
+                        data: b"matroska".to_vec(),
+                    }],
+                },
+                Element::Master {
+                    id: matroska::SEGMENT_ID,
+                    children: vec![
+                        Element::Master {
+                            id: matroska::INFO_ID,
+                            children: vec![
+                                Element::Raw {
+                                    id: matroska::TIMESTAMP_SCALE_ID,
+                                    data: vec![0x0F, 0x42, 0x40],
+                                },
+                                Element::Raw {
+                                    id: matroska::DURATION_ID,
+                                    data: 1500.0f64.to_be_bytes().to_vec(),
+                                },
+                                Element::Raw {
+                                    id: matroska::WRITING_APP_ID,
+                                    data: b"mkvedit".to_vec(),
+                                },
+                            ],
+                        },
+                        Element::Master {
+                            id: matroska::TRACKS_ID,
+                            children: vec![Element::Master {
+                                id: matroska::TRACK_ENTRY_ID,
+                                children: vec![
+                                    Element::Raw {
+                                        id: matroska::TRACK_NUMBER_ID,
+                                        data: vec![0x01],
+                                    },
+                                    Element::Raw {
+                                        id: matroska::TRACK_UID_ID,
+                                        data: vec![0x01],
+                                    },
+                                    Element::Raw {
+                                        id: matroska::TRACK_TYPE_ID,
+                                        data: vec![0x01],
+                                    },
+                                    Element::Raw {
+                                        id: matroska::CODEC_ID_ID,
+                                        data: b"V_AV1".to_vec(),
+                                    },
+                                ],
+                            }],
+                        },
+                    ],
+                },
+            ],
+        };
+
+        assert_eq!(
+            "MatroskaDocument
+├── EBML Header
+│   └── DocType: \"matroska\"
+└── Segment
+    ├── Info
+    │   ├── TimestampScale: 1000000
+    │   ├── Duration: 1500.0
+    │   └── WritingApp: \"mkvedit\"
+    └── Tracks
+        └── TrackEntry
+            ├── TrackNumber: 1
+            ├── TrackUID: 1
+            ├── TrackType: 1
+            └── CodecID: \"V_AV1\"
+",
+            print_bytes(&ebml_tree)
+        );
+    }
 }